@@ -1,11 +1,15 @@
 mod bindings;
 mod state;
-use state::{AppPlatform, BuildType, CustomInputs, Platform, PublishingFormat, Sdk, State};
+use state::{
+    AppPlatform, BuildType, CustomInputs, ExpoBuildTool, FlutterVersionSource, GradleDsl,
+    JdkDistribution, JdkVersion, KmpIosFramework, NodeVersionSource, PackageManager, Platform,
+    PublishingFormat, RunnerSize, Sdk, SigningStrategy, State, VersioningStrategy,
+};
 use std::fmt;
 use std::str::FromStr;
 use strum::IntoEnumIterator;
 use web_sys::HtmlInputElement as InputElement;
-use web_sys::{HtmlElement, HtmlSelectElement};
+use web_sys::{HtmlElement, HtmlSelectElement, HtmlTextAreaElement};
 use yew::prelude::*;
 use yew::{events::InputEvent, function_component, html, Component, Context, Html};
 use yew_hooks::prelude::*;
@@ -153,6 +157,7 @@ fn DisplayInfo(props: &DisplayInfoProps) -> Html {
 pub struct DisplayCodeProps {
     pub info: Option<String>,
     pub code: Option<String>,
+    pub platform: Platform,
 }
 
 #[function_component]
@@ -160,7 +165,7 @@ fn DisplayCode(props: &DisplayCodeProps) -> Html {
     let code_ref = use_node_ref();
 
     let options = bindings::HighlightOptions {
-        language: "yaml".to_owned(),
+        language: props.platform.code_language().to_owned(),
         ignore_illegals: true,
     };
 
@@ -177,7 +182,7 @@ fn DisplayCode(props: &DisplayCodeProps) -> Html {
         <>
             <DisplayInfo info={props.info.clone()} />
             <CopyToClipboardButton code={ props.code.clone() } />
-            <pre class="code"><label>{ "YAML" }</label><code ref={code_ref}></code></pre>
+            <pre class="code"><label>{ props.platform.code_label() }</label><code ref={code_ref}></code></pre>
         </>
     }
 }
@@ -190,8 +195,49 @@ enum Msg {
     UpdateBuildType(String),
     UpdateBuildVariantName(String),
     UpdateBuildVariantPath(String),
-    UpdatePublishingFormat(String),
+    UpdateBuildVariantBundlePath(String),
+    UpdateProductFlavors(String),
+    UpdateBuildTypes(String),
+    UpdateXcodeScheme(String),
+    UpdateXcodeProjectPath(String),
+    UpdateExpoBuildTool(String),
+    UpdateEasProfile(String),
+    UpdateKmpAndroidModule(String),
+    UpdateKmpSharedModule(String),
+    UpdateKmpIosFramework(String),
+    UpdateMauiProject(String),
+    UpdatePackageManager(String),
+    UpdateNodeVersionSource(String),
+    UpdateNodeVersion(String),
+    UpdateFlutterVersionSource(String),
+    UpdateFlutterVersion(String),
+    UpdateFlutterFlavor(String),
+    UpdateFlutterTarget(String),
+    UpdateDartDefines(String),
+    UpdateProjectRoot(String),
+    UpdateJdkVersion(String),
+    UpdateJdkDistribution(String),
+    UpdateAgpVersion(String),
+    UpdateRunnerSize(String),
     ToggleShowingVersions,
+    ToggleEasUpdate,
+    ToggleFlutterObfuscate,
+    ToggleDartDefinesFromFile,
+    ToggleGradleSetup,
+    ToggleGradleConfigurationCache,
+    ToggleGradleBuildCache,
+    ToggleGradleParallel,
+    ToggleUniversalApk,
+    ToggleAbiSplits,
+    ToggleAbiSplitsUniversal,
+    ToggleUploadMapping,
+    ToggleUploadNativeSymbols,
+    UpdateSigningStrategy(String),
+    UpdateGradleDsl(String),
+    ToggleVerifySignature,
+    UpdateVersioningStrategy(String),
+    UpdateVersionCodeOffset(String),
+    TogglePublishingFormat(PublishingFormat),
 }
 
 struct App {
@@ -213,8 +259,49 @@ impl Component for App {
             custom_inputs: CustomInputs {
                 build_variant_name: Some("Debug".to_string()),
                 build_variant_path: Some("debug/app-debug".to_string()),
-                publishing_format: PublishingFormat::Apk,
+                build_variant_bundle_path: Some("debug/app-debug".to_string()),
+                product_flavors: Some(String::new()),
+                build_types: Some("debug".to_string()),
+                publishing_formats: vec![PublishingFormat::Apk],
                 show_versions: true,
+                xcode_scheme: Some("App".to_string()),
+                xcode_project_path: Some("App.xcworkspace".to_string()),
+                expo_build_tool: ExpoBuildTool::EasLocal,
+                eas_profile: Some("preview".to_string()),
+                eas_update: false,
+                kmp_android_module: Some("androidApp".to_string()),
+                kmp_shared_module: Some("shared".to_string()),
+                kmp_ios_framework: KmpIosFramework::EmbedAndSign,
+                maui_project: Some(String::new()),
+                package_manager: PackageManager::Npm,
+                node_version_source: NodeVersionSource::Version,
+                node_version: Some("18".to_string()),
+                flutter_version_source: FlutterVersionSource::Channel,
+                flutter_version: Some("stable".to_string()),
+                flutter_flavor: Some(String::new()),
+                flutter_target: Some(String::new()),
+                flutter_obfuscate: false,
+                dart_defines: Some(String::new()),
+                dart_defines_from_file: false,
+                project_root: Some(String::new()),
+                jdk_version: JdkVersion::Jdk17,
+                jdk_distribution: JdkDistribution::Zulu,
+                agp_version: Some(String::new()),
+                gradle_setup: false,
+                gradle_configuration_cache: false,
+                gradle_build_cache: false,
+                gradle_parallel: false,
+                runner_size: RunnerSize::Default,
+                universal_apk: false,
+                abi_splits: false,
+                abi_splits_universal: false,
+                upload_mapping: false,
+                upload_native_symbols: false,
+                signing_strategy: SigningStrategy::KeyProperties,
+                gradle_dsl: GradleDsl::Groovy,
+                verify_signature: false,
+                versioning_strategy: VersioningStrategy::Manual,
+                version_code_offset: None,
             },
         };
 
@@ -227,27 +314,23 @@ impl Component for App {
             Msg::UpdateAppPlatform(selected) => {
                 self.state.clear_text();
                 self.state.app_platform = AppPlatform::from_str(&selected).unwrap();
+                self.state.reset_unsupported();
             }
             Msg::UpdatePlatform(selected) => {
                 self.state.clear_text();
                 self.state.platform = Platform::from_str(&selected).unwrap();
+                self.state.reset_unsupported();
             }
             Msg::UpdateSdk(selected) => {
                 self.state.clear_text();
                 self.state.sdk = Sdk::from_str(&selected).unwrap();
+                self.state.reset_xcode_project();
+                self.state.reset_publishing_formats();
             }
             Msg::UpdateBuildType(selected) => {
                 self.state.clear_text();
                 self.state.build_type = BuildType::from_str(&selected).unwrap();
-                if matches!(self.state.build_type, BuildType::Signed) {
-                    self.state.custom_inputs.build_variant_name = Some("Release".to_string());
-                    self.state.custom_inputs.build_variant_path =
-                        Some("release/app-prod-release".to_string())
-                } else {
-                    self.state.custom_inputs.build_variant_name = Some("Debug".to_string());
-                    self.state.custom_inputs.build_variant_path =
-                        Some("debug/app-debug".to_string())
-                }
+                self.state.reset_build_variant();
             }
             Msg::UpdateBuildVariantName(value) => {
                 self.state.clear_text();
@@ -257,16 +340,202 @@ impl Component for App {
                 self.state.clear_text();
                 self.state.custom_inputs.build_variant_path = Some(value);
             }
-            Msg::UpdatePublishingFormat(selected) => {
+            Msg::UpdateBuildVariantBundlePath(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.build_variant_bundle_path = Some(value);
+            }
+            Msg::UpdateProductFlavors(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.product_flavors = Some(value);
+            }
+            Msg::UpdateBuildTypes(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.build_types = Some(value);
+            }
+            Msg::UpdateXcodeScheme(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.xcode_scheme = Some(value);
+            }
+            Msg::UpdateXcodeProjectPath(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.xcode_project_path = Some(value);
+            }
+            Msg::UpdateExpoBuildTool(selected) => {
+                self.state.clear_text();
+                self.state.custom_inputs.expo_build_tool =
+                    ExpoBuildTool::from_str(&selected).unwrap();
+            }
+            Msg::UpdateEasProfile(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.eas_profile = Some(value);
+            }
+            Msg::UpdateKmpAndroidModule(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.kmp_android_module = Some(value);
+            }
+            Msg::UpdateKmpSharedModule(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.kmp_shared_module = Some(value);
+            }
+            Msg::UpdateKmpIosFramework(selected) => {
+                self.state.clear_text();
+                self.state.custom_inputs.kmp_ios_framework =
+                    KmpIosFramework::from_str(&selected).unwrap();
+            }
+            Msg::UpdateMauiProject(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.maui_project = Some(value);
+            }
+            Msg::UpdatePackageManager(selected) => {
+                self.state.clear_text();
+                self.state.custom_inputs.package_manager =
+                    PackageManager::from_str(&selected).unwrap();
+            }
+            Msg::UpdateNodeVersionSource(selected) => {
+                self.state.clear_text();
+                self.state.custom_inputs.node_version_source =
+                    NodeVersionSource::from_str(&selected).unwrap();
+            }
+            Msg::UpdateNodeVersion(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.node_version = Some(value);
+            }
+            Msg::UpdateFlutterVersionSource(selected) => {
+                self.state.clear_text();
+                self.state.custom_inputs.flutter_version_source =
+                    FlutterVersionSource::from_str(&selected).unwrap();
+                self.state.reset_flutter_version();
+            }
+            Msg::UpdateFlutterVersion(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.flutter_version = Some(value);
+            }
+            Msg::UpdateFlutterFlavor(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.flutter_flavor = Some(value);
+            }
+            Msg::UpdateFlutterTarget(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.flutter_target = Some(value);
+            }
+            Msg::UpdateDartDefines(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.dart_defines = Some(value);
+            }
+            Msg::UpdateProjectRoot(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.project_root = Some(value);
+            }
+            Msg::UpdateJdkVersion(selected) => {
+                self.state.clear_text();
+                self.state.custom_inputs.jdk_version = JdkVersion::from_str(&selected).unwrap();
+            }
+            Msg::UpdateJdkDistribution(selected) => {
+                self.state.clear_text();
+                self.state.custom_inputs.jdk_distribution =
+                    JdkDistribution::from_str(&selected).unwrap();
+            }
+            Msg::UpdateAgpVersion(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.agp_version = Some(value);
+                self.state.reset_jdk_version();
+            }
+            Msg::UpdateRunnerSize(selected) => {
                 self.state.clear_text();
-                self.state.custom_inputs.publishing_format =
-                    PublishingFormat::from_str(&selected).unwrap();
+                self.state.custom_inputs.runner_size = RunnerSize::from_str(&selected).unwrap();
             }
             Msg::ToggleShowingVersions => {
                 self.state.clear_text();
                 let status = !self.state.custom_inputs.show_versions;
                 self.state.custom_inputs.show_versions = status;
             }
+            Msg::ToggleEasUpdate => {
+                self.state.clear_text();
+                let status = !self.state.custom_inputs.eas_update;
+                self.state.custom_inputs.eas_update = status;
+            }
+            Msg::ToggleFlutterObfuscate => {
+                self.state.clear_text();
+                let status = !self.state.custom_inputs.flutter_obfuscate;
+                self.state.custom_inputs.flutter_obfuscate = status;
+            }
+            Msg::ToggleDartDefinesFromFile => {
+                self.state.clear_text();
+                let status = !self.state.custom_inputs.dart_defines_from_file;
+                self.state.custom_inputs.dart_defines_from_file = status;
+            }
+            Msg::ToggleGradleSetup => {
+                self.state.clear_text();
+                let status = !self.state.custom_inputs.gradle_setup;
+                self.state.custom_inputs.gradle_setup = status;
+            }
+            Msg::ToggleGradleConfigurationCache => {
+                self.state.clear_text();
+                let status = !self.state.custom_inputs.gradle_configuration_cache;
+                self.state.custom_inputs.gradle_configuration_cache = status;
+            }
+            Msg::ToggleGradleBuildCache => {
+                self.state.clear_text();
+                let status = !self.state.custom_inputs.gradle_build_cache;
+                self.state.custom_inputs.gradle_build_cache = status;
+            }
+            Msg::ToggleGradleParallel => {
+                self.state.clear_text();
+                let status = !self.state.custom_inputs.gradle_parallel;
+                self.state.custom_inputs.gradle_parallel = status;
+            }
+            Msg::TogglePublishingFormat(publishing_format) => {
+                self.state.clear_text();
+                self.state.toggle_publishing_format(publishing_format);
+            }
+            Msg::ToggleUniversalApk => {
+                self.state.clear_text();
+                let status = !self.state.custom_inputs.universal_apk;
+                self.state.custom_inputs.universal_apk = status;
+            }
+            Msg::ToggleAbiSplits => {
+                self.state.clear_text();
+                let status = !self.state.custom_inputs.abi_splits;
+                self.state.custom_inputs.abi_splits = status;
+            }
+            Msg::ToggleAbiSplitsUniversal => {
+                self.state.clear_text();
+                let status = !self.state.custom_inputs.abi_splits_universal;
+                self.state.custom_inputs.abi_splits_universal = status;
+            }
+            Msg::ToggleUploadMapping => {
+                self.state.clear_text();
+                let status = !self.state.custom_inputs.upload_mapping;
+                self.state.custom_inputs.upload_mapping = status;
+            }
+            Msg::ToggleUploadNativeSymbols => {
+                self.state.clear_text();
+                let status = !self.state.custom_inputs.upload_native_symbols;
+                self.state.custom_inputs.upload_native_symbols = status;
+            }
+            Msg::UpdateSigningStrategy(selected) => {
+                self.state.clear_text();
+                self.state.custom_inputs.signing_strategy =
+                    SigningStrategy::from_str(&selected).unwrap();
+            }
+            Msg::UpdateGradleDsl(selected) => {
+                self.state.clear_text();
+                self.state.custom_inputs.gradle_dsl = GradleDsl::from_str(&selected).unwrap();
+            }
+            Msg::ToggleVerifySignature => {
+                self.state.clear_text();
+                let status = !self.state.custom_inputs.verify_signature;
+                self.state.custom_inputs.verify_signature = status;
+            }
+            Msg::UpdateVersioningStrategy(selected) => {
+                self.state.clear_text();
+                self.state.custom_inputs.versioning_strategy =
+                    VersioningStrategy::from_str(&selected).unwrap();
+            }
+            Msg::UpdateVersionCodeOffset(value) => {
+                self.state.clear_text();
+                self.state.custom_inputs.version_code_offset = Some(value);
+            }
         }
 
         true
@@ -309,12 +578,170 @@ impl Component for App {
             Some(Msg::UpdateBuildVariantPath(input.value()))
         });
 
-        let _on_publishing_format_change = link.batch_callback(|e: InputEvent| {
+        let _on_build_variant_bundle_path_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateBuildVariantBundlePath(input.value()))
+        });
+
+        let _on_product_flavors_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateProductFlavors(input.value()))
+        });
+
+        let _on_build_types_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateBuildTypes(input.value()))
+        });
+
+        let _on_xcode_scheme_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateXcodeScheme(input.value()))
+        });
+
+        let _on_xcode_project_path_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateXcodeProjectPath(input.value()))
+        });
+
+        let _on_expo_build_tool_change = link.batch_callback(|e: InputEvent| {
+            e.prevent_default();
+            let input: HtmlSelectElement = e.target_unchecked_into();
+            Some(Msg::UpdateExpoBuildTool(input.value()))
+        });
+
+        let _on_eas_profile_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateEasProfile(input.value()))
+        });
+
+        let _on_kmp_android_module_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateKmpAndroidModule(input.value()))
+        });
+
+        let _on_kmp_shared_module_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateKmpSharedModule(input.value()))
+        });
+
+        let _on_kmp_ios_framework_change = link.batch_callback(|e: InputEvent| {
+            e.prevent_default();
+            let input: HtmlSelectElement = e.target_unchecked_into();
+            Some(Msg::UpdateKmpIosFramework(input.value()))
+        });
+
+        let _on_maui_project_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateMauiProject(input.value()))
+        });
+
+        let _on_package_manager_change = link.batch_callback(|e: InputEvent| {
+            e.prevent_default();
+            let input: HtmlSelectElement = e.target_unchecked_into();
+            Some(Msg::UpdatePackageManager(input.value()))
+        });
+
+        let _on_node_version_source_change = link.batch_callback(|e: InputEvent| {
+            e.prevent_default();
+            let input: HtmlSelectElement = e.target_unchecked_into();
+            Some(Msg::UpdateNodeVersionSource(input.value()))
+        });
+
+        let _on_node_version_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateNodeVersion(input.value()))
+        });
+
+        let _on_flutter_version_source_change = link.batch_callback(|e: InputEvent| {
+            e.prevent_default();
+            let input: HtmlSelectElement = e.target_unchecked_into();
+            Some(Msg::UpdateFlutterVersionSource(input.value()))
+        });
+
+        let _on_flutter_version_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateFlutterVersion(input.value()))
+        });
+
+        let _on_flutter_flavor_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateFlutterFlavor(input.value()))
+        });
+
+        let _on_flutter_target_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateFlutterTarget(input.value()))
+        });
+
+        let _on_dart_defines_change = link.batch_callback(|e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            Some(Msg::UpdateDartDefines(input.value()))
+        });
+
+        let _on_project_root_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateProjectRoot(input.value()))
+        });
+
+        let _on_jdk_version_change = link.batch_callback(|e: InputEvent| {
+            e.prevent_default();
+            let input: HtmlSelectElement = e.target_unchecked_into();
+            Some(Msg::UpdateJdkVersion(input.value()))
+        });
+
+        let _on_jdk_distribution_change = link.batch_callback(|e: InputEvent| {
+            e.prevent_default();
+            let input: HtmlSelectElement = e.target_unchecked_into();
+            Some(Msg::UpdateJdkDistribution(input.value()))
+        });
+
+        let _on_agp_version_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateAgpVersion(input.value()))
+        });
+
+        let _on_runner_size_change = link.batch_callback(|e: InputEvent| {
+            e.prevent_default();
+            let input: HtmlSelectElement = e.target_unchecked_into();
+            Some(Msg::UpdateRunnerSize(input.value()))
+        });
+
+        let _on_signing_strategy_change = link.batch_callback(|e: InputEvent| {
             e.prevent_default();
             let input: HtmlSelectElement = e.target_unchecked_into();
-            Some(Msg::UpdatePublishingFormat(input.value()))
+            Some(Msg::UpdateSigningStrategy(input.value()))
         });
 
+        let _on_gradle_dsl_change = link.batch_callback(|e: InputEvent| {
+            e.prevent_default();
+            let input: HtmlSelectElement = e.target_unchecked_into();
+            Some(Msg::UpdateGradleDsl(input.value()))
+        });
+
+        let _on_versioning_strategy_change = link.batch_callback(|e: InputEvent| {
+            e.prevent_default();
+            let input: HtmlSelectElement = e.target_unchecked_into();
+            Some(Msg::UpdateVersioningStrategy(input.value()))
+        });
+
+        let _on_version_code_offset_change = link.batch_callback(|e: InputEvent| {
+            let input: InputElement = e.target_unchecked_into();
+            Some(Msg::UpdateVersionCodeOffset(input.value()))
+        });
+
+        let is_eas_local_build = matches!(self.state.sdk, Sdk::Expo)
+            && matches!(
+                self.state.custom_inputs.expo_build_tool,
+                ExpoBuildTool::EasLocal
+            );
+
+        let uses_variant_matrix = self.state.uses_variant_matrix();
+        let min_jdk = self
+            .state
+            .min_jdk_for_agp()
+            .filter(|min_jdk| self.state.custom_inputs.jdk_version.major() < *min_jdk);
+        let invalid_secrets = self.state.invalid_dart_define_secrets();
+
         html! {
             <>
                 <Header />
@@ -328,8 +755,13 @@ impl Component for App {
 
                 <div class="pickers">
                 <div class="picker-wide">
-                <label for="app-platform">{"Platform"}<div class="sm-t">{ "(iOS workflows coming soon!)" }</div></label>
-                <select name="app-platform" oninput={_on_app_platform_change} value={ self.state.app_platform.to_string() }>{ for self.to_options(self.state.app_platform) }</select>
+                <label for="app-platform">{"Platform"}</label>
+                <select name="app-platform" oninput={_on_app_platform_change} value={ self.state.app_platform.to_string() }>{ for self.to_options(self.state.app_platform, |_| true) }</select>
+                </div>
+
+                <div class="picker-wider">
+                <label for="project-root">{"Project Root "}<span class="sm-t">{"(relative to the repository, leave empty if the app is at the root)"}</span></label>
+                <input id="project-root" oninput={_on_project_root_change} type="text" placeholder="apps/mobile" value={ self.state.custom_inputs.project_root.to_owned() } />
                 </div>
                 </div>
 
@@ -338,25 +770,194 @@ impl Component for App {
 
                 <div class="picker-wide">
                 <label for="ci-provider">{"CI Provider"}</label>
-                <select name="ci-provider" oninput={_on_platform_change} value={ self.state.platform.to_string() }>{ for self.to_options(self.state.platform) }</select>
+                <select name="ci-provider" oninput={_on_platform_change} value={ self.state.platform.to_string() }>{ for self.to_options(self.state.platform, |platform| platform.supports(self.state.app_platform)) }</select>
                 </div>
 
                 <div class="picker-wide">
                 <label for="sdk">{"SDK"}</label>
-                <select name="sdk" oninput={_on_sdk_change} value={ self.state.sdk.to_string() }>{ for self.to_options(self.state.sdk) }</select>
+                <select name="sdk" oninput={_on_sdk_change} value={ self.state.sdk.to_string() }>{ for self.to_options(self.state.sdk, |sdk| sdk.supports(self.state.app_platform, self.state.platform)) }</select>
                 </div>
 
+                if !matches!(self.state.platform, Platform::XcodeCloud) {
                 <div class="picker-wide">
                 <label for="build-type">{"Build Type "}<span class="sm-t">{"("}<a href="https://developer.android.com/studio/publish/app-signing">{"signing apps"}</a>{")"}</span></label>
-                <select name="build-type" oninput={_on_build_type_change} value={ self.state.build_type.to_string() }>{ for self.to_options(self.state.build_type) }</select>
+                <select name="build-type" oninput={_on_build_type_change} value={ self.state.build_type.to_string() }>{ for self.to_options(self.state.build_type, |build_type| build_type.supports(self.state.app_platform)) }</select>
                 </div>
+                }
 
                 </div>
 
+                if self.state.sdk.uses_package_manager() {
+                // Pickers (JavaScript) Section
+                <div class="pickers">
+
+                <div class="picker-wide">
+                <label for="package-manager">{"Package Manager"}</label>
+                <select name="package-manager" oninput={_on_package_manager_change} value={ self.state.custom_inputs.package_manager.to_string() }>{ for self.to_options(self.state.custom_inputs.package_manager, |_| true) }</select>
+                </div>
+
+                <div class="picker-wide">
+                <label for="node-version-source">{"Node Version"}</label>
+                <select name="node-version-source" oninput={_on_node_version_source_change} value={ self.state.custom_inputs.node_version_source.to_string() }>{ for self.to_options(self.state.custom_inputs.node_version_source, |_| true) }</select>
+                </div>
+
+                if matches!(self.state.custom_inputs.node_version_source, NodeVersionSource::Version) {
+                <div class="picker-wide">
+                <label for="node-version">{"Version"}if matches!(self.state.platform, Platform::XcodeCloud) {<span class="sm-t">{" (major version only)"}</span>}</label>
+                <input id="node-version" oninput={_on_node_version_change} type="text" value={ self.state.custom_inputs.node_version.to_owned() } />
+                </div>
+                }
+
+                </div>
+                }
+
+                if self.state.uses_jdk() {
+                // Pickers (JDK) Section
+                <div class="pickers">
+
+                <div class="picker-wide">
+                <label for="jdk-version">{"JDK Version"}</label>
+                <select name="jdk-version" oninput={_on_jdk_version_change} value={ self.state.custom_inputs.jdk_version.to_string() }>{ for self.to_options(self.state.custom_inputs.jdk_version, |_| true) }</select>
+                </div>
+
+                <div class="picker-wide">
+                <label for="jdk-distribution">{"JDK Distribution "}<span class="sm-t">{"("}<a href="https://github.com/actions/setup-java#supported-distributions">{"setup-java"}</a>{")"}</span></label>
+                <select name="jdk-distribution" oninput={_on_jdk_distribution_change} value={ self.state.custom_inputs.jdk_distribution.to_string() }>{ for self.to_options(self.state.custom_inputs.jdk_distribution, |_| true) }</select>
+                </div>
+
+                if !matches!(self.state.sdk, Sdk::Maui) {
+                <div class="picker-wide">
+                <label for="agp-version">{"AGP Version "}<span class="sm-t">{"(picks the JDK)"}</span></label>
+                <input id="agp-version" oninput={_on_agp_version_change} type="text" placeholder="8.2" value={ self.state.custom_inputs.agp_version.to_owned() } />
+                </div>
+                }
+
+                </div>
+
+                if let Some(min_jdk) = min_jdk {
+                <p class="warning sm-t">{ format!("Android Gradle Plugin {} requires JDK {} or newer, the build will fail with JDK {}.", self.state.custom_inputs.agp_version.as_deref().unwrap_or_default().trim(), min_jdk, self.state.custom_inputs.jdk_version) }</p>
+                }
+                }
+
+                if self.state.uses_gradle() {
+                // Pickers (Gradle) Section
+                <div class="pickers">
+
+                <div class="picker-wider">
+                <label for="runner-size">{"Gradle Memory "}<span class="sm-t">{"(sets "}<code>{"org.gradle.jvmargs"}</code>{" for the runner)"}</span></label>
+                <select name="runner-size" oninput={_on_runner_size_change} value={ self.state.custom_inputs.runner_size.to_string() }>{ for self.to_options(self.state.custom_inputs.runner_size, |_| true) }</select>
+                </div>
+
+                </div>
+
+                <label>
+                <input aria-labelledby="gradle-setup" type="checkbox" class="show-versions" name="gradle-setup" onclick={ctx.link().callback(|_| Msg::ToggleGradleSetup)} checked={ self.state.custom_inputs.gradle_setup.to_owned() } />
+                  {"Cache with "}<a href="https://github.com/gradle/actions/blob/main/docs/setup-gradle.md">{"setup-gradle"}</a>{", read-only outside the default branch"}
+                </label>
+                <label>
+                <input aria-labelledby="gradle-configuration-cache" type="checkbox" class="show-versions" name="gradle-configuration-cache" onclick={ctx.link().callback(|_| Msg::ToggleGradleConfigurationCache)} checked={ self.state.custom_inputs.gradle_configuration_cache.to_owned() } />
+                  {"Enable the "}<a href="https://docs.gradle.org/current/userguide/configuration_cache.html">{"configuration cache"}</a>
+                </label>
+                <label>
+                <input aria-labelledby="gradle-build-cache" type="checkbox" class="show-versions" name="gradle-build-cache" onclick={ctx.link().callback(|_| Msg::ToggleGradleBuildCache)} checked={ self.state.custom_inputs.gradle_build_cache.to_owned() } />
+                  {"Enable the "}<a href="https://docs.gradle.org/current/userguide/build_cache.html">{"build cache"}</a>
+                </label>
+                <label>
+                <input aria-labelledby="gradle-parallel" type="checkbox" class="show-versions" name="gradle-parallel" onclick={ctx.link().callback(|_| Msg::ToggleGradleParallel)} checked={ self.state.custom_inputs.gradle_parallel.to_owned() } />
+                  {"Build modules in parallel"}
+                </label>
+                }
+
+                if matches!(self.state.sdk, Sdk::Flutter) {
+                // Pickers (Flutter) Section
+                <div class="pickers">
+
+                <div class="picker-wide">
+                <label for="flutter-version-source">{"Flutter Version "}<span class="sm-t">{"("}<a href="https://fvm.app/">{"FVM"}</a>{")"}</span></label>
+                <select name="flutter-version-source" oninput={_on_flutter_version_source_change} value={ self.state.custom_inputs.flutter_version_source.to_string() }>{ for self.to_options(self.state.custom_inputs.flutter_version_source, |_| true) }</select>
+                </div>
+
+                if !matches!(self.state.custom_inputs.flutter_version_source, FlutterVersionSource::Fvm) {
+                <div class="picker-wide">
+                <label for="flutter-version">{ self.state.custom_inputs.flutter_version_source.to_string() }</label>
+                <input id="flutter-version" oninput={_on_flutter_version_change} type="text" value={ self.state.custom_inputs.flutter_version.to_owned() } />
+                </div>
+                }
+
+                <div class="picker-wide">
+                <label for="flutter-flavor">{"Flavor "}<span class="sm-t">{"("}<a href="https://docs.flutter.dev/deployment/flavors">{"flavors"}</a>{")"}</span></label>
+                <input id="flutter-flavor" oninput={_on_flutter_flavor_change} type="text" placeholder="prod" value={ self.state.custom_inputs.flutter_flavor.to_owned() } />
+                </div>
+
+                <div class="picker-wide">
+                <label for="flutter-target">{"Target "}<span class="sm-t">{"(entrypoint)"}</span></label>
+                <input id="flutter-target" oninput={_on_flutter_target_change} type="text" placeholder="lib/main_prod.dart" value={ self.state.custom_inputs.flutter_target.to_owned() } />
+                </div>
+
+                </div>
+
+                if matches!(self.state.app_platform, AppPlatform::Android) && matches!(self.state.build_type, BuildType::Signed) {
+                <label>
+                <input aria-labelledby="flutter-obfuscate" type="checkbox" class="show-versions" name="flutter-obfuscate" onclick={ctx.link().callback(|_| Msg::ToggleFlutterObfuscate)} checked={ self.state.custom_inputs.flutter_obfuscate.to_owned() } />
+                  {"Obfuscate Dart code and upload the split debug info ("}<a href="https://docs.flutter.dev/deployment/obfuscate">{"obfuscation"}</a>{")"}
+                </label>
+                }
+
+                if matches!(self.state.platform, Platform::GitHub) {
+                // Dart Defines Section
+                <div class="pickers">
+
+                <div class="picker-wider">
+                <label for="dart-defines">{"Dart Defines "}<span class="sm-t">{"(one "}<code>{"KEY=value"}</code>{" per line, use "}<code>{"KEY=secrets.NAME"}</code>{" to read a GitHub secret)"}</span></label>
+                <textarea id="dart-defines" oninput={_on_dart_defines_change} rows="3" placeholder="API_URL=https://api.example.com" value={ self.state.custom_inputs.dart_defines.to_owned() } />
+                </div>
+
+                </div>
+
+                if !invalid_secrets.is_empty() {
+                <p class="warning sm-t">{ format!("Secret names can only contain letters, digits and underscores and can't start with a digit, skipping {}.", invalid_secrets.join(", ")) }</p>
+                }
+
+                <label>
+                <input aria-labelledby="dart-defines-from-file" type="checkbox" class="show-versions" name="dart-defines-from-file" onclick={ctx.link().callback(|_| Msg::ToggleDartDefinesFromFile)} checked={ self.state.custom_inputs.dart_defines_from_file.to_owned() } />
+                  {"Write the defines to a JSON file and pass it with "}<code>{"--dart-define-from-file"}</code>
+                </label>
+                }
+                }
+
+                if matches!(self.state.app_platform, AppPlatform::Android) {
                 // Pickers (row 2) Section
                 <div class="pickers">
 
-                if !matches!(self.state.sdk, Sdk::Flutter) {
+                if matches!(self.state.sdk, Sdk::KotlinMultiplatform) {
+                        <div class="picker-wide">
+                        <label for="kmp-android-module">{"Android Module"}</label>
+                        <input id="kmp-android-module" oninput={_on_kmp_android_module_change} type="text" value={ self.state.custom_inputs.kmp_android_module.to_owned() } />
+                        </div>
+                }
+
+                if matches!(self.state.sdk, Sdk::Maui) {
+                        <div class="picker-wide">
+                        <label for="maui-project">{"Project File "}<span class="sm-t">{"(leave empty for a single project)"}</span></label>
+                        <input id="maui-project" oninput={_on_maui_project_change} type="text" placeholder="src/App/App.csproj" value={ self.state.custom_inputs.maui_project.to_owned() } />
+                        </div>
+                }
+
+                if matches!(self.state.sdk, Sdk::Native) {
+                        <div class="picker-wide">
+                        <label for="product-flavors">{"Product Flavors "}<span class="sm-t">{"(builds each flavor × build type)"}</span></label>
+                        <input id="product-flavors" oninput={_on_product_flavors_change} type="text" placeholder="free, paid" value={ self.state.custom_inputs.product_flavors.to_owned() } />
+                        </div>
+                }
+
+                if uses_variant_matrix {
+                        <div class="picker-wide">
+                        <label for="build-types">{"Build Types"}</label>
+                        <input id="build-types" oninput={_on_build_types_change} type="text" value={ self.state.custom_inputs.build_types.to_owned() } />
+                        </div>
+                }
+
+                if !matches!(self.state.sdk, Sdk::Flutter | Sdk::Maui | Sdk::Unity | Sdk::Tauri) && !is_eas_local_build && !uses_variant_matrix {
                         <div class="picker-wide">
                         <label for="build-variant">{"Build Variant "}<span class="sm-t">{"("}<a href="https://developer.android.com/studio/build/build-variants">{"build variants"}</a>{")"}</span></label>
                         <input id="build-variant" oninput={_on_build_variant_name_change} type="text" value={ self.state.custom_inputs.build_variant_name.to_owned() } />
@@ -365,29 +966,188 @@ impl Component for App {
 
                 <div class="picker-wider">
                 <label for="pub-format">{"Publishing Format"}</label>
-                <select aria-labelledby="pub-format" name="pub-format" oninput={_on_publishing_format_change} value={ self.state.custom_inputs.publishing_format.to_string() }>{ for self.to_options(self.state.custom_inputs.publishing_format) }</select>
+                <div class="publishing-formats">
+                { for PublishingFormat::iter().map(|publishing_format| html! {
+                    <label>
+                    <input aria-labelledby="pub-format" type="checkbox" class="show-versions" name="pub-format" onclick={ctx.link().callback(move |_| Msg::TogglePublishingFormat(publishing_format))} checked={ self.state.custom_inputs.publishing_formats.contains(&publishing_format) } />
+                      { publishing_format.to_string() }
+                    </label>
+                }) }
+                </div>
+                </div>
+
+                if self.state.supports_signing_strategy() {
+                <div class="picker-wide">
+                <label for="signing-strategy">{"Signing Secrets "}<span class="sm-t">{"("}<a href="https://docs.gradle.org/current/userguide/build_environment.html#sec:project_properties">{"project properties"}</a>{")"}</span></label>
+                <select name="signing-strategy" oninput={_on_signing_strategy_change} value={ self.state.custom_inputs.signing_strategy.to_string() }>{ for self.to_options(self.state.custom_inputs.signing_strategy, |_| true) }</select>
+                </div>
+                }
+
+                if self.state.supports_gradle_dsl() {
+                <div class="picker-wide">
+                <label for="gradle-dsl">{"Build Script "}<span class="sm-t">{"("}<a href="https://developer.android.com/build/migrate-to-kotlin-dsl">{"Kotlin DSL"}</a>{")"}</span></label>
+                <select name="gradle-dsl" oninput={_on_gradle_dsl_change} value={ self.state.custom_inputs.gradle_dsl.to_string() }>{ for self.to_options(self.state.custom_inputs.gradle_dsl, |_| true) }</select>
+                </div>
+                }
+
+                </div>
+
+                if self.state.supports_universal_apk() {
+                <label>
+                <input aria-labelledby="universal-apk" type="checkbox" class="show-versions" name="universal-apk" onclick={ctx.link().callback(|_| Msg::ToggleUniversalApk)} checked={ self.state.custom_inputs.universal_apk.to_owned() } />
+                  {"Also upload a universal APK built from the AAB with "}<a href="https://developer.android.com/tools/bundletool">{"bundletool"}</a>
+                </label>
+                }
+
+                if self.state.supports_abi_splits() {
+                <label>
+                <input aria-labelledby="abi-splits" type="checkbox" class="show-versions" name="abi-splits" onclick={ctx.link().callback(|_| Msg::ToggleAbiSplits)} checked={ self.state.custom_inputs.abi_splits.to_owned() } />
+                  {"Upload an APK for each ABI with "}<a href="https://developer.android.com/build/configure-apk-splits">{"ABI splits"}</a>
+                </label>
+                }
+
+                if self.state.supports_abi_splits() && self.state.custom_inputs.abi_splits && !matches!(self.state.sdk, Sdk::Flutter) {
+                <label>
+                <input aria-labelledby="abi-splits-universal" type="checkbox" class="show-versions" name="abi-splits-universal" onclick={ctx.link().callback(|_| Msg::ToggleAbiSplitsUniversal)} checked={ self.state.custom_inputs.abi_splits_universal.to_owned() } />
+                  {"Include the universal APK of the splits"}
+                </label>
+                }
+
+                if self.state.supports_debug_symbols() {
+                <label>
+                <input aria-labelledby="upload-mapping" type="checkbox" class="show-versions" name="upload-mapping" onclick={ctx.link().callback(|_| Msg::ToggleUploadMapping)} checked={ self.state.custom_inputs.upload_mapping.to_owned() } />
+                  {"Upload the "}<a href="https://developer.android.com/build/shrink-code#decode-stack-trace">{"R8 mapping file"}</a>
+                </label>
+                <label>
+                <input aria-labelledby="upload-native-symbols" type="checkbox" class="show-versions" name="upload-native-symbols" onclick={ctx.link().callback(|_| Msg::ToggleUploadNativeSymbols)} checked={ self.state.custom_inputs.upload_native_symbols.to_owned() } />
+                  {"Upload the "}<a href="https://developer.android.com/build/include-native-symbols">{"native debug symbols"}</a>
+                </label>
+                }
+
+                if self.state.supports_signature_verification() {
+                <label>
+                <input aria-labelledby="verify-signature" type="checkbox" class="show-versions" name="verify-signature" onclick={ctx.link().callback(|_| Msg::ToggleVerifySignature)} checked={ self.state.custom_inputs.verify_signature.to_owned() } />
+                  {"Verify the signature with "}<a href="https://developer.android.com/tools/apksigner">{"apksigner"}</a>{" and add a SHA-256 checksum"}
+                </label>
+                }
+
+                if matches!(self.state.sdk, Sdk::Expo) {
+                // Pickers (Expo) Section
+                <div class="pickers">
+
+                <div class="picker-wide">
+                <label for="expo-build-tool">{"Build Tool "}<span class="sm-t">{"("}<a href="https://docs.expo.dev/build-reference/local-builds/">{"local builds"}</a>{")"}</span></label>
+                <select name="expo-build-tool" oninput={_on_expo_build_tool_change} value={ self.state.custom_inputs.expo_build_tool.to_string() }>{ for self.to_options(self.state.custom_inputs.expo_build_tool, |_| true) }</select>
                 </div>
 
+                if is_eas_local_build || self.state.custom_inputs.eas_update {
+                <div class="picker-wide">
+                <label for="eas-profile">{"EAS Profile "}<span class="sm-t">{"(from eas.json)"}</span></label>
+                <input id="eas-profile" oninput={_on_eas_profile_change} type="text" value={ self.state.custom_inputs.eas_profile.to_owned() } />
                 </div>
+                }
+
+                </div>
+
+                <label>
+                <input aria-labelledby="eas-update" type="checkbox" class="show-versions" name="eas-update" onclick={ctx.link().callback(|_| Msg::ToggleEasUpdate)} checked={ self.state.custom_inputs.eas_update.to_owned() } />
+                  {"Publish an "}<code>{"eas update"}</code>{" after the build"}
+                </label>
+                }
 
+                if !is_eas_local_build && !uses_variant_matrix && !matches!(self.state.sdk, Sdk::Flutter | Sdk::Maui | Sdk::Unity | Sdk::Tauri) {
                 // Pickers (row 3) Section
                 <div class="pickers">
 
+                if self.state.custom_inputs.publishing_formats.contains(&PublishingFormat::Apk) {
                 <div class="picker-wider">
-                <label for="output-path">{"Build Output Path "}<div class="sm-t">{"(relative path to the base output directory)"}</div></label>
+                <label for="output-path">{"APK Output Path "}<div class="sm-t">{"(relative path to the base output directory)"}</div></label>
                 <div class="input-wrapper suffix">
                 <input aria-labelledby="output-path" id="output-path" oninput={_on_build_variant_path_change} class="build-variant" type="text" value={ self.state.custom_inputs.build_variant_path.to_owned() } />
-                <div class="input-suffix">{ "." }{self.state.custom_inputs.publishing_format.to_string().to_lowercase()}</div>
+                <div class="input-suffix">{ ".apk" }</div>
+                </div>
+                </div>
+                }
+
+                if self.state.custom_inputs.publishing_formats.contains(&PublishingFormat::Aab) {
+                <div class="picker-wider">
+                <label for="bundle-output-path">{"AAB Output Path "}<div class="sm-t">{"(relative path to the base output directory)"}</div></label>
+                <div class="input-wrapper suffix">
+                <input aria-labelledby="bundle-output-path" id="bundle-output-path" oninput={_on_build_variant_bundle_path_change} class="build-variant" type="text" value={ self.state.custom_inputs.build_variant_bundle_path.to_owned() } />
+                <div class="input-suffix">{ ".aab" }</div>
+                </div>
+                </div>
+                }
+
+                </div>
+                }
+                }
+
+                if matches!(self.state.app_platform, AppPlatform::Ios) && matches!(self.state.platform, Platform::GitHub) && !matches!(self.state.sdk, Sdk::Flutter) {
+                // Pickers (iOS) Section
+                <div class="pickers">
+
+                <div class="picker-wide">
+                <label for="xcode-scheme">{"Scheme"}</label>
+                <input id="xcode-scheme" oninput={_on_xcode_scheme_change} type="text" value={ self.state.custom_inputs.xcode_scheme.to_owned() } />
+                </div>
+
+                <div class="picker-wider">
+                <label for="xcode-project-path">{"Workspace or Project "}<div class="sm-t">{"(.xcworkspace or .xcodeproj, relative to the iOS project directory)"}</div></label>
+                <input aria-labelledby="xcode-project-path" id="xcode-project-path" oninput={_on_xcode_project_path_change} type="text" value={ self.state.custom_inputs.xcode_project_path.to_owned() } />
+                </div>
+
+                </div>
+                }
+
+                if matches!(self.state.app_platform, AppPlatform::Ios) && matches!(self.state.sdk, Sdk::KotlinMultiplatform) {
+                // Pickers (Kotlin Multiplatform iOS) Section
+                <div class="pickers">
+
+                <div class="picker-wide">
+                <label for="kmp-ios-framework">{"Shared Framework "}<span class="sm-t">{"("}<a href="https://kotlinlang.org/docs/multiplatform-ios-integration-overview.html">{"iOS integration"}</a>{")"}</span></label>
+                <select name="kmp-ios-framework" oninput={_on_kmp_ios_framework_change} value={ self.state.custom_inputs.kmp_ios_framework.to_string() }>{ for self.to_options(self.state.custom_inputs.kmp_ios_framework, |_| true) }</select>
                 </div>
+
+                if matches!(self.state.custom_inputs.kmp_ios_framework, KmpIosFramework::XcFramework) {
+                <div class="picker-wide">
+                <label for="kmp-shared-module">{"Shared Module"}</label>
+                <input id="kmp-shared-module" oninput={_on_kmp_shared_module_change} type="text" value={ self.state.custom_inputs.kmp_shared_module.to_owned() } />
                 </div>
+                }
 
                 </div>
+                }
 
                 // Supporting Config Checkbox
                 <label>
                 <input aria-labelledby="show-versions" type="checkbox" class="show-versions" name="show-versions" onclick={ctx.link().callback(|_| Msg::ToggleShowingVersions)} checked={ self.state.custom_inputs.show_versions.to_owned() } />
-                  {"Include supporting configuration for "}<code>{"versionCode"}</code>{" & "}<code>{"versionName"}</code>
+                  {"Include supporting configuration for "}
+                  if matches!(self.state.app_platform, AppPlatform::Ios) {
+                      <code>{"CFBundleVersion"}</code>{" & "}<code>{"CFBundleShortVersionString"}</code>
+                  } else {
+                      <code>{"versionCode"}</code>{" & "}<code>{"versionName"}</code>
+                  }
                 </label>
+
+                if self.state.custom_inputs.show_versions && self.state.supports_versioning_strategy() {
+                // Pickers (Versioning) Section
+                <div class="pickers">
+
+                <div class="picker-wide">
+                <label for="versioning-strategy">{"Versioning Strategy"}</label>
+                <select name="versioning-strategy" oninput={_on_versioning_strategy_change} value={ self.state.custom_inputs.versioning_strategy.to_string() }>{ for self.to_options(self.state.custom_inputs.versioning_strategy, |_| true) }</select>
+                </div>
+
+                if matches!(self.state.custom_inputs.versioning_strategy, VersioningStrategy::RunNumber) {
+                <div class="picker-wide">
+                <label for="version-code-offset">{"Run Number Offset "}<span class="sm-t">{"(added to the versionCode)"}</span></label>
+                <input id="version-code-offset" oninput={_on_version_code_offset_change} type="text" placeholder="0" value={ self.state.custom_inputs.version_code_offset.to_owned() } />
+                </div>
+                }
+
+                </div>
+                }
                 </div>
 
                 // CTA
@@ -395,7 +1155,7 @@ impl Component for App {
 
                 // Info + Code
                 <div class="section">
-                <DisplayCode code={ self.state.code_template.to_owned() } info={ self.state.info_template.to_owned() } />
+                <DisplayCode code={ self.state.code_template.to_owned() } info={ self.state.info_template.to_owned() } platform={ self.state.platform } />
                 </div>
 
                 </main>
@@ -409,8 +1169,9 @@ impl App {
     fn to_options<E: fmt::Display + Eq + IntoEnumIterator>(
         &self,
         cur: E,
+        is_supported: impl Fn(&E) -> bool,
     ) -> impl Iterator<Item = Html> {
-        E::iter().map(move |val| {
+        E::iter().filter(is_supported).map(move |val| {
             let is_selected = val == cur;
 
             html! {
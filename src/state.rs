@@ -1,5 +1,6 @@
 use askama::Template;
 use serde_derive::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter, EnumString};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -17,8 +18,49 @@ pub struct State {
 pub struct CustomInputs {
     pub build_variant_name: Option<String>,
     pub build_variant_path: Option<String>,
-    pub publishing_format: PublishingFormat,
+    pub build_variant_bundle_path: Option<String>,
+    pub product_flavors: Option<String>,
+    pub build_types: Option<String>,
+    pub publishing_formats: Vec<PublishingFormat>,
     pub show_versions: bool,
+    pub xcode_scheme: Option<String>,
+    pub xcode_project_path: Option<String>,
+    pub expo_build_tool: ExpoBuildTool,
+    pub eas_profile: Option<String>,
+    pub eas_update: bool,
+    pub kmp_android_module: Option<String>,
+    pub kmp_shared_module: Option<String>,
+    pub kmp_ios_framework: KmpIosFramework,
+    pub maui_project: Option<String>,
+    pub package_manager: PackageManager,
+    pub node_version_source: NodeVersionSource,
+    pub node_version: Option<String>,
+    pub flutter_version_source: FlutterVersionSource,
+    pub flutter_version: Option<String>,
+    pub flutter_flavor: Option<String>,
+    pub flutter_target: Option<String>,
+    pub flutter_obfuscate: bool,
+    pub dart_defines: Option<String>,
+    pub dart_defines_from_file: bool,
+    pub project_root: Option<String>,
+    pub jdk_version: JdkVersion,
+    pub jdk_distribution: JdkDistribution,
+    pub agp_version: Option<String>,
+    pub gradle_setup: bool,
+    pub gradle_configuration_cache: bool,
+    pub gradle_build_cache: bool,
+    pub gradle_parallel: bool,
+    pub runner_size: RunnerSize,
+    pub universal_apk: bool,
+    pub abi_splits: bool,
+    pub abi_splits_universal: bool,
+    pub upload_mapping: bool,
+    pub upload_native_symbols: bool,
+    pub signing_strategy: SigningStrategy,
+    pub gradle_dsl: GradleDsl,
+    pub verify_signature: bool,
+    pub versioning_strategy: VersioningStrategy,
+    pub version_code_offset: Option<String>,
 }
 
 impl State {
@@ -27,298 +69,2678 @@ impl State {
         self.code_template = Some(String::new());
     }
 
+    pub fn reset_build_variant(&mut self) {
+        if matches!(self.build_type, BuildType::Signed) {
+            self.custom_inputs.build_variant_name = Some("Release".to_string());
+            self.custom_inputs.build_variant_path = Some("release/app-prod-release".to_string());
+            self.custom_inputs.build_variant_bundle_path =
+                Some("release/app-prod-release".to_string());
+            self.custom_inputs.build_types = Some("release".to_string());
+            self.custom_inputs.eas_profile = Some("production".to_string())
+        } else {
+            self.custom_inputs.build_variant_name = Some("Debug".to_string());
+            self.custom_inputs.build_variant_path = Some("debug/app-debug".to_string());
+            self.custom_inputs.build_variant_bundle_path = Some("debug/app-debug".to_string());
+            self.custom_inputs.build_types = Some("debug".to_string());
+            self.custom_inputs.eas_profile = Some("preview".to_string())
+        }
+    }
+
+    pub fn reset_xcode_project(&mut self) {
+        if matches!(self.sdk, Sdk::KotlinMultiplatform) {
+            self.custom_inputs.xcode_scheme = Some("iosApp".to_string());
+            self.custom_inputs.xcode_project_path = Some("iosApp.xcodeproj".to_string())
+        } else {
+            self.custom_inputs.xcode_scheme = Some("App".to_string());
+            self.custom_inputs.xcode_project_path = Some("App.xcworkspace".to_string())
+        }
+    }
+
+    // An EAS profile or a Unity player build produces a single format
+    pub fn supports_multiple_publishing_formats(&self) -> bool {
+        !matches!(self.sdk, Sdk::Expo | Sdk::Unity)
+    }
+
+    pub fn toggle_publishing_format(&mut self, publishing_format: PublishingFormat) {
+        let supports_multiple = self.supports_multiple_publishing_formats();
+        let formats = &mut self.custom_inputs.publishing_formats;
+
+        if !supports_multiple {
+            *formats = vec![publishing_format];
+        } else if !formats.contains(&publishing_format) {
+            formats.push(publishing_format);
+            // Kept in declaration order so the workflow steps don't depend on the click order
+            formats.sort_by_key(|format| *format as u8);
+        } else if formats.len() > 1 {
+            formats.retain(|format| *format != publishing_format);
+        }
+    }
+
+    pub fn reset_publishing_formats(&mut self) {
+        if !self.supports_multiple_publishing_formats() {
+            self.custom_inputs.publishing_formats.truncate(1);
+        }
+    }
+
+    pub fn reset_flutter_version(&mut self) {
+        match self.custom_inputs.flutter_version_source {
+            FlutterVersionSource::Channel => {
+                self.custom_inputs.flutter_version = Some("stable".to_string())
+            }
+            FlutterVersionSource::Version => {
+                self.custom_inputs.flutter_version = Some("3.24.5".to_string())
+            }
+            FlutterVersionSource::Fvm => self.custom_inputs.flutter_version = None,
+        }
+    }
+
+    pub fn uses_variant_matrix(&self) -> bool {
+        matches!(self.sdk, Sdk::Native)
+            && !split_list(
+                self.custom_inputs
+                    .product_flavors
+                    .as_deref()
+                    .unwrap_or_default(),
+            )
+            .is_empty()
+    }
+
+    pub fn reset_jdk_version(&mut self) {
+        if let Some(min_jdk) = self.min_jdk_for_agp() {
+            if let Some(jdk_version) = JdkVersion::iter().find(|jdk| jdk.major() >= min_jdk) {
+                self.custom_inputs.jdk_version = jdk_version;
+            }
+        }
+    }
+
+    pub fn min_jdk_for_agp(&self) -> Option<u8> {
+        let agp_version = self
+            .custom_inputs
+            .agp_version
+            .as_deref()
+            .unwrap_or_default();
+        let mut parts = agp_version
+            .trim()
+            .split('.')
+            .map(|part| part.parse::<u32>());
+        let major = parts.next()?.ok()?;
+        let minor = parts.next().and_then(|part| part.ok()).unwrap_or(0);
+
+        AGP_MIN_JDK
+            .iter()
+            .find(|(agp, _)| (major, minor) >= *agp)
+            .map(|(_, jdk)| *jdk)
+    }
+
+    pub fn uses_jdk(&self) -> bool {
+        matches!(self.platform, Platform::GitHub)
+            && !matches!(self.sdk, Sdk::Unity)
+            && (matches!(self.app_platform, AppPlatform::Android)
+                || matches!(self.sdk, Sdk::KotlinMultiplatform))
+    }
+
+    pub fn uses_gradle(&self) -> bool {
+        self.uses_jdk() && !matches!(self.sdk, Sdk::Maui)
+    }
+
+    // bundletool reuses the keystore and key.properties that the signed templates decode
+    pub fn supports_universal_apk(&self) -> bool {
+        matches!(self.build_type, BuildType::Signed)
+            && self
+                .custom_inputs
+                .publishing_formats
+                .contains(&PublishingFormat::Aab)
+            && matches!(
+                self.sdk,
+                Sdk::Native
+                    | Sdk::Flutter
+                    | Sdk::ReactNative
+                    | Sdk::KotlinMultiplatform
+                    | Sdk::Capacitor
+                    | Sdk::Tauri
+            )
+    }
+
+    // Split APKs come from the splits block of the Gradle build, or from --split-per-abi in Flutter
+    pub fn supports_abi_splits(&self) -> bool {
+        matches!(self.app_platform, AppPlatform::Android)
+            && matches!(self.platform, Platform::GitHub)
+            && self
+                .custom_inputs
+                .publishing_formats
+                .contains(&PublishingFormat::Apk)
+            && matches!(
+                self.sdk,
+                Sdk::Native
+                    | Sdk::Flutter
+                    | Sdk::ReactNative
+                    | Sdk::KotlinMultiplatform
+                    | Sdk::Capacitor
+            )
+    }
+
+    // Only release builds are minified with R8 and strip the native libraries
+    pub fn supports_debug_symbols(&self) -> bool {
+        matches!(self.build_type, BuildType::Signed)
+            && matches!(
+                self.sdk,
+                Sdk::Native
+                    | Sdk::Flutter
+                    | Sdk::ReactNative
+                    | Sdk::KotlinMultiplatform
+                    | Sdk::Capacitor
+                    | Sdk::Tauri
+            )
+    }
+
+    // Only the templates that read the version from env variables can compute it
+    pub fn supports_versioning_strategy(&self) -> bool {
+        matches!(self.app_platform, AppPlatform::Android)
+            && matches!(self.platform, Platform::GitHub)
+            && matches!(
+                self.sdk,
+                Sdk::Native
+                    | Sdk::Flutter
+                    | Sdk::ReactNative
+                    | Sdk::Expo
+                    | Sdk::KotlinMultiplatform
+                    | Sdk::Capacitor
+            )
+    }
+
+    pub fn supports_signature_verification(&self) -> bool {
+        matches!(self.build_type, BuildType::Signed)
+            && matches!(
+                self.sdk,
+                Sdk::Native
+                    | Sdk::Flutter
+                    | Sdk::ReactNative
+                    | Sdk::Expo
+                    | Sdk::KotlinMultiplatform
+                    | Sdk::Capacitor
+                    | Sdk::Tauri
+            )
+    }
+
+    // Expo's EAS builds sign with credentials.json, so only the prebuild tool runs Gradle itself
+    pub fn supports_signing_strategy(&self) -> bool {
+        matches!(self.build_type, BuildType::Signed)
+            && match self.sdk {
+                Sdk::Native
+                | Sdk::Flutter
+                | Sdk::ReactNative
+                | Sdk::KotlinMultiplatform
+                | Sdk::Capacitor
+                | Sdk::Tauri => true,
+                Sdk::Expo => matches!(self.custom_inputs.expo_build_tool, ExpoBuildTool::Prebuild),
+                _ => false,
+            }
+    }
+
+    // KMP and Tauri projects are generated with the Kotlin DSL, and Flutter only needs
+    // a build file snippet for signing
+    pub fn supports_gradle_dsl(&self) -> bool {
+        matches!(self.app_platform, AppPlatform::Android)
+            && matches!(self.platform, Platform::GitHub)
+            && match self.sdk {
+                Sdk::Native | Sdk::ReactNative | Sdk::Capacitor => true,
+                Sdk::Flutter => matches!(self.build_type, BuildType::Signed),
+                _ => false,
+            }
+    }
+
+    pub fn reset_unsupported(&mut self) {
+        if !self.platform.supports(self.app_platform) {
+            self.platform = Platform::iter()
+                .find(|platform| platform.supports(self.app_platform))
+                .unwrap();
+        }
+        if !self.sdk.supports(self.app_platform, self.platform) {
+            self.sdk = Sdk::iter()
+                .find(|sdk| sdk.supports(self.app_platform, self.platform))
+                .unwrap();
+            self.reset_xcode_project();
+            self.reset_publishing_formats();
+        }
+        if !self.build_type.supports(self.app_platform) {
+            self.build_type = BuildType::Unsigned;
+            self.reset_build_variant();
+        }
+    }
+
     pub fn gen_templates(&mut self) {
-        let (code_template, info_template) = match (self.platform, self.sdk, self.build_type) {
-            (Platform::GitHub, Sdk::Native, BuildType::Signed) => {
+        let (code_template, info_template) = match (self.app_platform, self.platform) {
+            (AppPlatform::Android, Platform::GitHub) => self.gen_github_android_templates(),
+            (AppPlatform::Ios, Platform::GitHub) => self.gen_github_ios_templates(),
+            (_, Platform::XcodeCloud) => self.gen_xcode_cloud_templates(),
+        };
+
+        self.code_template = code_template;
+        self.info_template = info_template;
+    }
+
+    fn gen_github_android_templates(&self) -> (Option<String>, Option<String>) {
+        match (self.sdk, self.build_type) {
+            (Sdk::Native, BuildType::Signed) => {
                 let code_template = self.github_native_signed();
                 let info_template = self.github_native_signed_info();
                 (Some(code_template), Some(info_template))
             }
-            (Platform::GitHub, Sdk::Flutter, BuildType::Signed) => {
+            (Sdk::Flutter, BuildType::Signed) => {
                 let code_template = self.github_flutter_signed();
                 let info_template = self.github_flutter_signed_info();
                 (Some(code_template), Some(info_template))
             }
-            (Platform::GitHub, Sdk::ReactNative, BuildType::Signed) => {
+            (Sdk::ReactNative, BuildType::Signed) => {
                 let code_template = self.github_react_native_signed();
                 let info_template = self.github_react_native_signed_info();
                 (Some(code_template), Some(info_template))
             }
-            (Platform::GitHub, Sdk::Native, BuildType::Unsigned) => {
+            (Sdk::Expo, BuildType::Signed) => {
+                let code_template = self.github_expo_signed();
+                let info_template = self.github_expo_signed_info();
+                (Some(code_template), Some(info_template))
+            }
+            (Sdk::KotlinMultiplatform, BuildType::Signed) => {
+                let code_template = self.github_kotlin_multiplatform_signed();
+                let info_template = self.github_kotlin_multiplatform_signed_info();
+                (Some(code_template), Some(info_template))
+            }
+            (Sdk::Maui, BuildType::Signed) => {
+                let code_template = self.github_maui_signed();
+                let info_template = self.github_maui_signed_info();
+                (Some(code_template), Some(info_template))
+            }
+            (Sdk::Capacitor, BuildType::Signed) => {
+                let code_template = self.github_capacitor_signed();
+                let info_template = self.github_capacitor_signed_info();
+                (Some(code_template), Some(info_template))
+            }
+            (Sdk::Unity, BuildType::Signed) => {
+                let code_template = self.github_unity_signed();
+                let info_template = self.github_unity_signed_info();
+                (Some(code_template), Some(info_template))
+            }
+            (Sdk::Tauri, BuildType::Signed) => {
+                let code_template = self.github_tauri_signed();
+                let info_template = self.github_tauri_signed_info();
+                (Some(code_template), Some(info_template))
+            }
+            (Sdk::Native, BuildType::Unsigned) => {
                 let code_template = self.github_native_unsigned();
-                (Some(code_template), None)
+                let info_template = self.github_gradle_unsigned_info();
+                (Some(code_template), info_template)
             }
-            (Platform::GitHub, Sdk::Flutter, BuildType::Unsigned) => {
+            (Sdk::Flutter, BuildType::Unsigned) => {
                 let code_template = self.github_flutter_unsigned();
-                (Some(code_template), None)
+                let info_template = self.github_gradle_unsigned_info();
+                (Some(code_template), info_template)
             }
-            (Platform::GitHub, Sdk::ReactNative, BuildType::Unsigned) => {
+            (Sdk::ReactNative, BuildType::Unsigned) => {
                 let code_template = self.github_react_native_unsigned();
+                let info_template = self.github_gradle_unsigned_info();
+                (Some(code_template), info_template)
+            }
+            (Sdk::Expo, BuildType::Unsigned) => {
+                let code_template = self.github_expo_unsigned();
+                let info_template = self.github_expo_unsigned_info();
+                (Some(code_template), Some(info_template))
+            }
+            (Sdk::KotlinMultiplatform, BuildType::Unsigned) => {
+                let code_template = self.github_kotlin_multiplatform_unsigned();
+                let info_template = self.github_gradle_unsigned_info();
+                (Some(code_template), info_template)
+            }
+            (Sdk::Maui, BuildType::Unsigned) => {
+                let code_template = self.github_maui_unsigned();
+                (Some(code_template), None)
+            }
+            (Sdk::Capacitor, BuildType::Unsigned) => {
+                let code_template = self.github_capacitor_unsigned();
+                let info_template = self.github_gradle_unsigned_info();
+                (Some(code_template), info_template)
+            }
+            (Sdk::Unity, BuildType::Unsigned) => {
+                let code_template = self.github_unity_unsigned();
+                let info_template = self.github_unity_unsigned_info();
+                (Some(code_template), Some(info_template))
+            }
+            (Sdk::Tauri, BuildType::Unsigned) => {
+                let code_template = self.github_tauri_unsigned();
+                let info_template = self.github_gradle_unsigned_info();
+                (Some(code_template), info_template)
+            }
+        }
+    }
+
+    fn gen_github_ios_templates(&self) -> (Option<String>, Option<String>) {
+        match (self.sdk, self.build_type) {
+            (Sdk::Native, BuildType::Unsigned) => {
+                let code_template = self.github_ios_native_unsigned();
+                (Some(code_template), None)
+            }
+            (Sdk::Flutter, BuildType::Unsigned) => {
+                let code_template = self.github_ios_flutter_unsigned();
+                (Some(code_template), None)
+            }
+            (Sdk::ReactNative, BuildType::Unsigned) => {
+                let code_template = self.github_ios_react_native_unsigned();
                 (Some(code_template), None)
             }
+            (Sdk::KotlinMultiplatform, BuildType::Unsigned) => {
+                let code_template = self.github_ios_kotlin_multiplatform_unsigned();
+                let info_template = self.github_gradle_unsigned_info();
+                (Some(code_template), info_template)
+            }
+            _ => (None, None),
+        }
+    }
+
+    fn gen_xcode_cloud_templates(&self) -> (Option<String>, Option<String>) {
+        match self.sdk {
+            Sdk::Native => {
+                let code_template = self.xcode_cloud_native();
+                let info_template = self.xcode_cloud_native_info();
+                (Some(code_template), Some(info_template))
+            }
+            Sdk::Flutter => {
+                let code_template = self.xcode_cloud_flutter();
+                let info_template = self.xcode_cloud_flutter_info();
+                (Some(code_template), Some(info_template))
+            }
+            Sdk::ReactNative => {
+                let code_template = self.xcode_cloud_react_native();
+                let info_template = self.xcode_cloud_react_native_info();
+                (Some(code_template), Some(info_template))
+            }
+            _ => (None, None),
+        }
+    }
+
+    fn uses_abi_splits(&self) -> bool {
+        self.custom_inputs.abi_splits && self.supports_abi_splits()
+    }
+
+    // Flutter never builds a universal APK alongside the split ones
+    fn excludes_universal_split(&self) -> bool {
+        self.uses_abi_splits()
+            && !self.custom_inputs.abi_splits_universal
+            && !matches!(self.sdk, Sdk::Flutter)
+    }
+
+    // Split APKs are uploaded with a wildcard in place of the ABI, so the path has no extension
+    fn build_variant_apk_path(&self) -> String {
+        let path = self
+            .custom_inputs
+            .build_variant_path
+            .as_deref()
+            .unwrap_or_default();
+
+        if self.uses_abi_splits() {
+            abi_split_path(path)
+        } else {
+            path.to_string()
+        }
+    }
+
+    // Gradle names the output directories of a variant in camel case, e.g. prodRelease
+    fn build_variant_dir(&self) -> String {
+        let name = self
+            .custom_inputs
+            .build_variant_name
+            .as_deref()
+            .unwrap_or_default();
+        let mut chars = name.chars();
+        match chars.next() {
+            Some(first) => first.to_lowercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    }
+
+    fn needs_eas_cli(&self) -> bool {
+        matches!(self.custom_inputs.expo_build_tool, ExpoBuildTool::EasLocal)
+            || self.custom_inputs.eas_update
+    }
+
+    // Every product flavor × build type pair becomes a cell of the workflow's build matrix
+    fn build_variants(&self) -> Vec<BuildVariant> {
+        if !self.uses_variant_matrix() {
+            return Vec::new();
+        }
+
+        let flavors = split_list(
+            self.custom_inputs
+                .product_flavors
+                .as_deref()
+                .unwrap_or_default(),
+        );
+        let mut build_types = split_list(
+            self.custom_inputs
+                .build_types
+                .as_deref()
+                .unwrap_or_default(),
+        );
+        if build_types.is_empty() {
+            build_types = match self.build_type {
+                BuildType::Signed => vec!["release"],
+                BuildType::Unsigned => vec!["debug"],
+            };
+        }
+
+        let mut variants = Vec::new();
+        for flavor in &flavors {
+            for build_type in &build_types {
+                let name = format!("{}{}", flavor, capitalize(build_type));
+                let apk_path = format!("{}/{}/app-{}-{}", flavor, build_type, flavor, build_type);
+                variants.push(BuildVariant {
+                    task: capitalize(&name),
+                    apk_path: if self.uses_abi_splits() {
+                        abi_split_path(&apk_path)
+                    } else {
+                        apk_path
+                    },
+                    aab_path: format!("{}/app-{}-{}", name, flavor, build_type),
+                    name,
+                });
+            }
+        }
+        variants
+    }
+
+    // Written to the runner's ~/.gradle/gradle.properties, which overrides the project's own
+    fn gradle_properties(&self) -> Vec<String> {
+        let mut properties = Vec::new();
+        let jvmargs = self.custom_inputs.runner_size.gradle_jvmargs();
+
+        if !jvmargs.is_empty() {
+            properties.push(format!("org.gradle.jvmargs={}", jvmargs));
+        }
+        if self.custom_inputs.gradle_parallel {
+            properties.push("org.gradle.parallel=true".to_string());
+        }
+        if self.custom_inputs.gradle_build_cache {
+            properties.push("org.gradle.caching=true".to_string());
+        }
+        if self.custom_inputs.gradle_configuration_cache {
+            properties.push("org.gradle.configuration-cache=true".to_string());
+        }
+        properties
+    }
+
+    fn gradle_info(&self) -> String {
+        if !self.custom_inputs.gradle_setup && self.gradle_properties().is_empty() {
+            return String::new();
+        }
+
+        GradleOptionsInfo {
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            gradle_configuration_cache: &self.custom_inputs.gradle_configuration_cache,
+            gradle_build_cache: &self.custom_inputs.gradle_build_cache,
+            gradle_parallel: &self.custom_inputs.gradle_parallel,
+            gradle_jvmargs: self.custom_inputs.runner_size.gradle_jvmargs(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn abi_splits_info(&self) -> String {
+        if !self.uses_abi_splits() {
+            return String::new();
+        }
+
+        AbiSplitsInfo {
+            flutter: &matches!(self.sdk, Sdk::Flutter),
+            gradle_dsl: &self.gradle_dsl(),
+            abi_splits_universal: &self.custom_inputs.abi_splits_universal,
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn uses_gradle_signing(&self) -> bool {
+        matches!(
+            self.custom_inputs.signing_strategy,
+            SigningStrategy::GradleProperties
+        ) && self.supports_signing_strategy()
+    }
+
+    fn versioning_strategy(&self) -> VersioningStrategy {
+        if self.custom_inputs.show_versions && self.supports_versioning_strategy() {
+            self.custom_inputs.versioning_strategy
+        } else {
+            VersioningStrategy::Manual
+        }
+    }
+
+    // An offset of 0 is left out so the step stays a plain run number
+    fn version_code_offset(&self) -> String {
+        self.custom_inputs
+            .version_code_offset
+            .as_deref()
+            .and_then(|offset| offset.trim().parse::<u32>().ok())
+            .filter(|offset| *offset > 0)
+            .map(|offset| offset.to_string())
+            .unwrap_or_default()
+    }
+
+    fn versioning_info(&self) -> String {
+        if matches!(self.versioning_strategy(), VersioningStrategy::Manual) {
+            return String::new();
+        }
+
+        VersioningStrategyInfo {
+            versioning_strategy: &self.versioning_strategy(),
+            version_code_offset: &self.version_code_offset(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn signature_verification_info(&self) -> String {
+        if !(self.custom_inputs.verify_signature && self.supports_signature_verification()) {
+            return String::new();
+        }
+
+        SignatureVerificationInfo {}.render().unwrap()
+    }
+
+    fn gradle_dsl(&self) -> GradleDsl {
+        match self.sdk {
+            Sdk::KotlinMultiplatform | Sdk::Tauri => GradleDsl::Kotlin,
+            _ => self.custom_inputs.gradle_dsl,
+        }
+    }
+
+    fn gradle_signing_info(&self, build_file: &str) -> String {
+        if !self.uses_gradle_signing() {
+            return String::new();
+        }
+
+        GradleSigningInfo {
+            build_file,
+            kotlin_dsl: &build_file.ends_with(".kts"),
+            injected: &matches!(self.sdk, Sdk::Expo),
+        }
+        .render()
+        .unwrap()
+    }
+
+    // How the build reads the versions passed by the workflow, which the signed guides cover too
+    fn unsigned_versioning_info(&self) -> String {
+        if !self.custom_inputs.show_versions {
+            return String::new();
+        }
+
+        match self.sdk {
+            Sdk::Native => GithubNativeUnsignedInfo {
+                show_versions: &self.custom_inputs.show_versions,
+                gradle_dsl: &self.gradle_dsl(),
+            }
+            .render()
+            .unwrap(),
+            Sdk::Flutter => GithubFlutterUnsignedInfo {
+                show_versions: &self.custom_inputs.show_versions,
+            }
+            .render()
+            .unwrap(),
+            Sdk::ReactNative => GithubReactNativeUnsignedInfo {
+                show_versions: &self.custom_inputs.show_versions,
+                gradle_dsl: &self.gradle_dsl(),
+            }
+            .render()
+            .unwrap(),
+            _ => String::new(),
+        }
+    }
+
+    // Unsigned builds have no signing setup, so the versioning and Gradle options make up the guide
+    fn github_gradle_unsigned_info(&self) -> Option<String> {
+        let unsigned_versioning_info = self.unsigned_versioning_info();
+        let versioning_info = self.versioning_info();
+        let abi_splits_info = self.abi_splits_info();
+        let gradle_info = self.gradle_info();
+        if unsigned_versioning_info.is_empty()
+            && versioning_info.is_empty()
+            && abi_splits_info.is_empty()
+            && gradle_info.is_empty()
+        {
+            return None;
+        }
+
+        Some(
+            GithubGradleUnsignedInfo {
+                unsigned_versioning_info: &unsigned_versioning_info,
+                versioning_info: &versioning_info,
+                abi_splits_info: &abi_splits_info,
+                gradle_info: &gradle_info,
+            }
+            .render()
+            .unwrap(),
+        )
+    }
+
+    fn flutter_flavor(&self) -> &str {
+        self.custom_inputs
+            .flutter_flavor
+            .as_deref()
+            .unwrap_or_default()
+            .trim()
+    }
+
+    // Appended to every `flutter build` command, so it's either empty or starts with a space
+    fn flutter_build_flags(&self) -> String {
+        let mut flags = String::new();
+        let flavor = self.flutter_flavor();
+        let target = self
+            .custom_inputs
+            .flutter_target
+            .as_deref()
+            .unwrap_or_default()
+            .trim();
+
+        if !flavor.is_empty() {
+            flags.push_str(&format!(" --flavor {}", flavor));
+        }
+        if !target.is_empty() {
+            flags.push_str(&format!(" --target {}", target));
+        }
+        flags
+    }
+
+    // Secret names become environment variables, so names the shell can't expand are left out
+    pub fn invalid_dart_define_secrets(&self) -> Vec<&str> {
+        self.parse_dart_defines()
+            .into_iter()
+            .filter_map(|(_, value)| match value {
+                DartDefineValue::Secret(secret) if !is_env_name(secret) => Some(secret),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn dart_defines(&self) -> Vec<(&str, DartDefineValue<'_>)> {
+        self.parse_dart_defines()
+            .into_iter()
+            .filter(|(_, value)| match value {
+                DartDefineValue::Secret(secret) => is_env_name(secret),
+                DartDefineValue::Literal(_) => true,
+            })
+            .collect()
+    }
+
+    // One define per line as KEY=value, where a value of secrets.NAME reads the GitHub secret NAME
+    fn parse_dart_defines(&self) -> Vec<(&str, DartDefineValue<'_>)> {
+        self.custom_inputs
+            .dart_defines
+            .as_deref()
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| (key.trim(), value.trim()))
+            .filter(|(key, _)| !key.is_empty())
+            .map(|(key, value)| match value.strip_prefix("secrets.") {
+                Some(secret) => (key, DartDefineValue::Secret(secret)),
+                None => (key, DartDefineValue::Literal(value)),
+            })
+            .collect()
+    }
+
+    // Secrets are passed to the build through environment variables named after them
+    fn dart_define_secrets(&self) -> Vec<String> {
+        let mut secrets: Vec<String> = Vec::new();
+        for (_, value) in self.dart_defines() {
+            if let DartDefineValue::Secret(secret) = value {
+                if !secrets.iter().any(|s| s == secret) {
+                    secrets.push(secret.to_string());
+                }
+            }
+        }
+        secrets
+    }
+
+    // Appended to the `flutter build` command, so it's either empty or starts with a space
+    fn dart_define_flags(&self) -> String {
+        let defines = self.dart_defines();
+        if defines.is_empty() {
+            return String::new();
+        }
+        if self.custom_inputs.dart_defines_from_file {
+            return " --dart-define-from-file=dart-defines.json".to_string();
+        }
+
+        defines
+            .iter()
+            .map(|(key, value)| match value {
+                DartDefineValue::Literal(literal) => {
+                    format!(
+                        " --dart-define={}",
+                        shell_quote(&format!("{}={}", key, literal))
+                    )
+                }
+                DartDefineValue::Secret(secret) => {
+                    format!(
+                        " --dart-define={}\"${}\"",
+                        shell_quote(&format!("{}=", key)),
+                        secret
+                    )
+                }
+            })
+            .collect()
+    }
+
+    // Arguments for `jq`, which writes every define into the file read by --dart-define-from-file
+    fn dart_defines_file_args(&self) -> String {
+        if !self.custom_inputs.dart_defines_from_file {
+            return String::new();
+        }
+
+        self.dart_defines()
+            .iter()
+            .map(|(key, value)| match value {
+                DartDefineValue::Literal(literal) => {
+                    format!(" --arg {} {}", shell_quote(key), shell_quote(literal))
+                }
+                DartDefineValue::Secret(secret) => {
+                    format!(" --arg {} \"${}\"", shell_quote(key), secret)
+                }
+            })
+            .collect()
+    }
+
+    // The Gradle variant that `flutter build` runs, which names the bundle and mapping directories
+    fn flutter_variant(&self) -> String {
+        let flavor = self.flutter_flavor();
+        match (self.build_type, flavor.is_empty()) {
+            (BuildType::Signed, true) => "release".to_string(),
+            (BuildType::Signed, false) => format!("{}Release", flavor),
+            (BuildType::Unsigned, true) => "debug".to_string(),
+            (BuildType::Unsigned, false) => format!("{}Debug", flavor),
+        }
+    }
+
+    // Flutter names its outputs after the flavor and build mode, relative to build/app/outputs
+    fn flutter_build_path(&self, publishing_format: PublishingFormat) -> String {
+        let flavor = self.flutter_flavor();
+        let mode = match self.build_type {
+            BuildType::Signed => "release",
+            BuildType::Unsigned => "debug",
+        };
+        let name = if flavor.is_empty() {
+            format!("app-{}", mode)
+        } else {
+            format!("app-{}-{}", flavor, mode)
         };
 
-        self.code_template = code_template;
-        self.info_template = info_template;
+        match publishing_format {
+            PublishingFormat::Apk if self.uses_abi_splits() => {
+                format!("flutter-apk/{}", abi_split_path(&name))
+            }
+            PublishingFormat::Apk => format!("flutter-apk/{}", name),
+            PublishingFormat::Aab => format!("bundle/{}/{}", self.flutter_variant(), name),
+        }
+    }
+
+    // Expo and Unity templates build the first selected format only
+    fn publishing_format(&self) -> PublishingFormat {
+        self.custom_inputs.publishing_formats[0]
+    }
+
+    // Homebrew only has a formula per major version, so 20.11.1 installs node@20
+    fn node_major_version(&self) -> String {
+        let major: String = self
+            .custom_inputs
+            .node_version
+            .as_deref()
+            .unwrap_or_default()
+            .trim()
+            .trim_start_matches('v')
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+
+        if major.is_empty() {
+            "18".to_string()
+        } else {
+            major
+        }
+    }
+
+    // Templates prefix paths with the project root, so it's either empty or ends with a slash
+    fn project_root(&self) -> String {
+        let root = self
+            .custom_inputs
+            .project_root
+            .as_deref()
+            .unwrap_or_default()
+            .trim()
+            .trim_start_matches("./")
+            .trim_matches('/');
+
+        if root.is_empty() {
+            String::new()
+        } else {
+            format!("{}/", root)
+        }
+    }
+
+    fn github_native_signed_info(&self) -> String {
+        GithubNativeSignedInfo {
+            show_versions: &self.custom_inputs.show_versions.to_owned(),
+            gradle_dsl: &self.gradle_dsl(),
+            versioning_info: &self.versioning_info(),
+            signature_verification_info: &self.signature_verification_info(),
+            abi_splits_info: &self.abi_splits_info(),
+            gradle_signing_info: &self
+                .gradle_signing_info(&format!("app/{}", self.gradle_dsl().build_file())),
+            gradle_info: &self.gradle_info(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_flutter_signed_info(&self) -> String {
+        GithubFlutterSignedInfo {
+            show_versions: &self.custom_inputs.show_versions.to_owned(),
+            gradle_dsl: &self.gradle_dsl(),
+            flutter_obfuscate: &self.custom_inputs.flutter_obfuscate,
+            versioning_info: &self.versioning_info(),
+            signature_verification_info: &self.signature_verification_info(),
+            abi_splits_info: &self.abi_splits_info(),
+            gradle_signing_info: &self
+                .gradle_signing_info(&format!("android/app/{}", self.gradle_dsl().build_file())),
+            gradle_info: &self.gradle_info(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_react_native_signed_info(&self) -> String {
+        GithubReactNativeSignedInfo {
+            show_versions: &self.custom_inputs.show_versions.to_owned(),
+            gradle_dsl: &self.gradle_dsl(),
+            versioning_info: &self.versioning_info(),
+            signature_verification_info: &self.signature_verification_info(),
+            abi_splits_info: &self.abi_splits_info(),
+            gradle_signing_info: &self
+                .gradle_signing_info(&format!("android/app/{}", self.gradle_dsl().build_file())),
+            gradle_info: &self.gradle_info(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_expo_signed_info(&self) -> String {
+        GithubExpoSignedInfo {
+            show_versions: &self.custom_inputs.show_versions.to_owned(),
+            expo_build_tool: &self.custom_inputs.expo_build_tool,
+            eas_update: &self.custom_inputs.eas_update,
+            eas_cli: &self.needs_eas_cli(),
+            gradle_signing_info: &self.gradle_signing_info(""),
+            versioning_info: &self.versioning_info(),
+            signature_verification_info: &self.signature_verification_info(),
+            gradle_info: &self.gradle_info(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_expo_unsigned_info(&self) -> String {
+        GithubExpoUnsignedInfo {
+            show_versions: &self.custom_inputs.show_versions.to_owned(),
+            expo_build_tool: &self.custom_inputs.expo_build_tool,
+            eas_update: &self.custom_inputs.eas_update,
+            eas_cli: &self.needs_eas_cli(),
+            versioning_info: &self.versioning_info(),
+            gradle_info: &self.gradle_info(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_kotlin_multiplatform_signed_info(&self) -> String {
+        GithubKotlinMultiplatformSignedInfo {
+            show_versions: &self.custom_inputs.show_versions.to_owned(),
+            kmp_android_module: &self
+                .custom_inputs
+                .kmp_android_module
+                .as_ref()
+                .unwrap_or(&String::new()),
+            versioning_info: &self.versioning_info(),
+            signature_verification_info: &self.signature_verification_info(),
+            abi_splits_info: &self.abi_splits_info(),
+            gradle_signing_info: &self.gradle_signing_info(&format!(
+                "{}/build.gradle.kts",
+                self.custom_inputs
+                    .kmp_android_module
+                    .as_ref()
+                    .unwrap_or(&String::new())
+            )),
+            gradle_info: &self.gradle_info(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_maui_signed_info(&self) -> String {
+        GithubMauiSignedInfo {
+            show_versions: &self.custom_inputs.show_versions.to_owned(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_capacitor_signed_info(&self) -> String {
+        GithubCapacitorSignedInfo {
+            show_versions: &self.custom_inputs.show_versions.to_owned(),
+            gradle_dsl: &self.gradle_dsl(),
+            versioning_info: &self.versioning_info(),
+            signature_verification_info: &self.signature_verification_info(),
+            abi_splits_info: &self.abi_splits_info(),
+            gradle_signing_info: &self
+                .gradle_signing_info(&format!("android/app/{}", self.gradle_dsl().build_file())),
+            gradle_info: &self.gradle_info(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_unity_signed_info(&self) -> String {
+        GithubUnitySignedInfo {
+            show_versions: &self.custom_inputs.show_versions.to_owned(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_unity_unsigned_info(&self) -> String {
+        GithubUnityUnsignedInfo {
+            show_versions: &self.custom_inputs.show_versions.to_owned(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_tauri_signed_info(&self) -> String {
+        GithubTauriSignedInfo {
+            show_versions: &self.custom_inputs.show_versions.to_owned(),
+            gradle_signing_info: &self
+                .gradle_signing_info("src-tauri/gen/android/app/build.gradle.kts"),
+            signature_verification_info: &self.signature_verification_info(),
+            gradle_info: &self.gradle_info(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn xcode_cloud_native_info(&self) -> String {
+        XcodeCloudNativeInfo {
+            show_versions: &self.custom_inputs.show_versions.to_owned(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn xcode_cloud_flutter_info(&self) -> String {
+        XcodeCloudFlutterInfo {
+            show_versions: &self.custom_inputs.show_versions.to_owned(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn xcode_cloud_react_native_info(&self) -> String {
+        XcodeCloudReactNativeInfo {
+            show_versions: &self.custom_inputs.show_versions.to_owned(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_native_signed(&self) -> String {
+        GithubNativeSigned {
+            title: "Android release build",
+            publishing_formats: &self.custom_inputs.publishing_formats,
+            show_versions: &self.custom_inputs.show_versions,
+            versioning_strategy: &self.versioning_strategy(),
+            version_code_offset: &self.version_code_offset(),
+            build_variant_name: &self
+                .custom_inputs
+                .build_variant_name
+                .as_ref()
+                .unwrap_or(&String::new()),
+            build_variant_path: &self.build_variant_apk_path(),
+            build_variant_bundle_path: &self
+                .custom_inputs
+                .build_variant_bundle_path
+                .as_ref()
+                .unwrap_or(&String::new()),
+            variants: &self.build_variants(),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            universal_apk: &(self.custom_inputs.universal_apk && self.supports_universal_apk()),
+            exclude_universal_split: &self.excludes_universal_split(),
+            build_variant_dir: &self.build_variant_dir(),
+            upload_mapping: &(self.custom_inputs.upload_mapping && self.supports_debug_symbols()),
+            upload_native_symbols: &(self.custom_inputs.upload_native_symbols
+                && self.supports_debug_symbols()),
+            gradle_signing: &self.uses_gradle_signing(),
+            verify_signature: &(self.custom_inputs.verify_signature
+                && self.supports_signature_verification()),
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_flutter_signed(&self) -> String {
+        GithubFlutterSigned {
+            title: "Flutter Android release build",
+            publishing_formats: &self.custom_inputs.publishing_formats,
+            show_versions: &self.custom_inputs.show_versions,
+            versioning_strategy: &self.versioning_strategy(),
+            version_code_offset: &self.version_code_offset(),
+            flutter_apk_path: &self.flutter_build_path(PublishingFormat::Apk),
+            flutter_aab_path: &self.flutter_build_path(PublishingFormat::Aab),
+            flutter_build_flags: &self.flutter_build_flags(),
+            abi_splits: &self.uses_abi_splits(),
+            dart_define_flags: &self.dart_define_flags(),
+            dart_define_secrets: &self.dart_define_secrets(),
+            dart_defines_file_args: &self.dart_defines_file_args(),
+            flutter_obfuscate: &self.custom_inputs.flutter_obfuscate,
+            flutter_version_source: &self.custom_inputs.flutter_version_source,
+            flutter_version: &self
+                .custom_inputs
+                .flutter_version
+                .as_ref()
+                .unwrap_or(&String::new()),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            universal_apk: &(self.custom_inputs.universal_apk && self.supports_universal_apk()),
+            flutter_variant: &self.flutter_variant(),
+            upload_mapping: &(self.custom_inputs.upload_mapping && self.supports_debug_symbols()),
+            upload_native_symbols: &(self.custom_inputs.upload_native_symbols
+                && self.supports_debug_symbols()),
+            gradle_signing: &self.uses_gradle_signing(),
+            verify_signature: &(self.custom_inputs.verify_signature
+                && self.supports_signature_verification()),
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_react_native_signed(&self) -> String {
+        GithubReactNativeSigned {
+            title: "React Native Android release build",
+            publishing_formats: &self.custom_inputs.publishing_formats,
+            show_versions: &self.custom_inputs.show_versions,
+            versioning_strategy: &self.versioning_strategy(),
+            version_code_offset: &self.version_code_offset(),
+            build_variant_name: &self
+                .custom_inputs
+                .build_variant_name
+                .as_ref()
+                .unwrap_or(&String::new()),
+            build_variant_path: &self.build_variant_apk_path(),
+            build_variant_bundle_path: &self
+                .custom_inputs
+                .build_variant_bundle_path
+                .as_ref()
+                .unwrap_or(&String::new()),
+            package_manager: &self.custom_inputs.package_manager,
+            node_version_source: &self.custom_inputs.node_version_source,
+            node_version: &self
+                .custom_inputs
+                .node_version
+                .as_ref()
+                .unwrap_or(&String::new()),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            universal_apk: &(self.custom_inputs.universal_apk && self.supports_universal_apk()),
+            exclude_universal_split: &self.excludes_universal_split(),
+            build_variant_dir: &self.build_variant_dir(),
+            upload_mapping: &(self.custom_inputs.upload_mapping && self.supports_debug_symbols()),
+            upload_native_symbols: &(self.custom_inputs.upload_native_symbols
+                && self.supports_debug_symbols()),
+            gradle_signing: &self.uses_gradle_signing(),
+            verify_signature: &(self.custom_inputs.verify_signature
+                && self.supports_signature_verification()),
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_native_unsigned(&self) -> String {
+        GithubNativeUnsigned {
+            title: "Android debug build",
+            publishing_formats: &self.custom_inputs.publishing_formats,
+            show_versions: &self.custom_inputs.show_versions,
+            versioning_strategy: &self.versioning_strategy(),
+            version_code_offset: &self.version_code_offset(),
+            build_variant_name: &self
+                .custom_inputs
+                .build_variant_name
+                .as_ref()
+                .unwrap_or(&String::new()),
+            build_variant_path: &self.build_variant_apk_path(),
+            build_variant_bundle_path: &self
+                .custom_inputs
+                .build_variant_bundle_path
+                .as_ref()
+                .unwrap_or(&String::new()),
+            variants: &self.build_variants(),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            exclude_universal_split: &self.excludes_universal_split(),
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_flutter_unsigned(&self) -> String {
+        GithubFlutterUnsigned {
+            title: "Flutter Android debug build",
+            publishing_formats: &self.custom_inputs.publishing_formats,
+            show_versions: &self.custom_inputs.show_versions,
+            versioning_strategy: &self.versioning_strategy(),
+            version_code_offset: &self.version_code_offset(),
+            flutter_apk_path: &self.flutter_build_path(PublishingFormat::Apk),
+            flutter_aab_path: &self.flutter_build_path(PublishingFormat::Aab),
+            flutter_build_flags: &self.flutter_build_flags(),
+            abi_splits: &self.uses_abi_splits(),
+            dart_define_flags: &self.dart_define_flags(),
+            dart_define_secrets: &self.dart_define_secrets(),
+            dart_defines_file_args: &self.dart_defines_file_args(),
+            flutter_version_source: &self.custom_inputs.flutter_version_source,
+            flutter_version: &self
+                .custom_inputs
+                .flutter_version
+                .as_ref()
+                .unwrap_or(&String::new()),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_react_native_unsigned(&self) -> String {
+        GithubReactNativeUnsigned {
+            title: "React Native Android debug build",
+            publishing_formats: &self.custom_inputs.publishing_formats,
+            show_versions: &self.custom_inputs.show_versions,
+            versioning_strategy: &self.versioning_strategy(),
+            version_code_offset: &self.version_code_offset(),
+            build_variant_name: &self
+                .custom_inputs
+                .build_variant_name
+                .as_ref()
+                .unwrap_or(&String::new()),
+            build_variant_path: &self.build_variant_apk_path(),
+            build_variant_bundle_path: &self
+                .custom_inputs
+                .build_variant_bundle_path
+                .as_ref()
+                .unwrap_or(&String::new()),
+            package_manager: &self.custom_inputs.package_manager,
+            node_version_source: &self.custom_inputs.node_version_source,
+            node_version: &self
+                .custom_inputs
+                .node_version
+                .as_ref()
+                .unwrap_or(&String::new()),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            exclude_universal_split: &self.excludes_universal_split(),
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_expo_signed(&self) -> String {
+        GithubExpoSigned {
+            title: "Expo Android release build",
+            publishing_format: &self.publishing_format(),
+            show_versions: &self.custom_inputs.show_versions,
+            versioning_strategy: &self.versioning_strategy(),
+            version_code_offset: &self.version_code_offset(),
+            build_variant_name: &self
+                .custom_inputs
+                .build_variant_name
+                .as_ref()
+                .unwrap_or(&String::new()),
+            build_variant_path: &self
+                .custom_inputs
+                .build_variant_path
+                .as_ref()
+                .unwrap_or(&String::new()),
+            build_variant_bundle_path: &self
+                .custom_inputs
+                .build_variant_bundle_path
+                .as_ref()
+                .unwrap_or(&String::new()),
+            expo_build_tool: &self.custom_inputs.expo_build_tool,
+            eas_profile: &self
+                .custom_inputs
+                .eas_profile
+                .as_ref()
+                .unwrap_or(&String::new()),
+            eas_update: &self.custom_inputs.eas_update,
+            eas_cli: &self.needs_eas_cli(),
+            package_manager: &self.custom_inputs.package_manager,
+            node_version_source: &self.custom_inputs.node_version_source,
+            node_version: &self
+                .custom_inputs
+                .node_version
+                .as_ref()
+                .unwrap_or(&String::new()),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            gradle_signing: &self.uses_gradle_signing(),
+            verify_signature: &(self.custom_inputs.verify_signature
+                && self.supports_signature_verification()),
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_expo_unsigned(&self) -> String {
+        GithubExpoUnsigned {
+            title: "Expo Android debug build",
+            publishing_format: &self.publishing_format(),
+            show_versions: &self.custom_inputs.show_versions,
+            versioning_strategy: &self.versioning_strategy(),
+            version_code_offset: &self.version_code_offset(),
+            build_variant_name: &self
+                .custom_inputs
+                .build_variant_name
+                .as_ref()
+                .unwrap_or(&String::new()),
+            build_variant_path: &self
+                .custom_inputs
+                .build_variant_path
+                .as_ref()
+                .unwrap_or(&String::new()),
+            build_variant_bundle_path: &self
+                .custom_inputs
+                .build_variant_bundle_path
+                .as_ref()
+                .unwrap_or(&String::new()),
+            expo_build_tool: &self.custom_inputs.expo_build_tool,
+            eas_profile: &self
+                .custom_inputs
+                .eas_profile
+                .as_ref()
+                .unwrap_or(&String::new()),
+            eas_update: &self.custom_inputs.eas_update,
+            eas_cli: &self.needs_eas_cli(),
+            package_manager: &self.custom_inputs.package_manager,
+            node_version_source: &self.custom_inputs.node_version_source,
+            node_version: &self
+                .custom_inputs
+                .node_version
+                .as_ref()
+                .unwrap_or(&String::new()),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_ios_native_unsigned(&self) -> String {
+        GithubIosNativeUnsigned {
+            title: "iOS simulator build",
+            show_versions: &self.custom_inputs.show_versions,
+            xcode_scheme: &self
+                .custom_inputs
+                .xcode_scheme
+                .as_ref()
+                .unwrap_or(&String::new()),
+            xcode_project_path: &self
+                .custom_inputs
+                .xcode_project_path
+                .as_ref()
+                .unwrap_or(&String::new()),
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
     }
 
-    fn github_native_signed_info(&self) -> String {
-        GithubNativeSignedInfo {
-            show_versions: &self.custom_inputs.show_versions.to_owned(),
+    fn github_ios_flutter_unsigned(&self) -> String {
+        GithubIosFlutterUnsigned {
+            title: "Flutter iOS simulator build",
+            show_versions: &self.custom_inputs.show_versions,
+            flutter_build_flags: &self.flutter_build_flags(),
+            dart_define_flags: &self.dart_define_flags(),
+            dart_define_secrets: &self.dart_define_secrets(),
+            dart_defines_file_args: &self.dart_defines_file_args(),
+            flutter_version_source: &self.custom_inputs.flutter_version_source,
+            flutter_version: &self
+                .custom_inputs
+                .flutter_version
+                .as_ref()
+                .unwrap_or(&String::new()),
+            project_root: &self.project_root(),
         }
         .render()
         .unwrap()
     }
 
-    fn github_flutter_signed_info(&self) -> String {
-        GithubFlutterSignedInfo {
-            show_versions: &self.custom_inputs.show_versions.to_owned(),
+    fn github_ios_react_native_unsigned(&self) -> String {
+        GithubIosReactNativeUnsigned {
+            title: "React Native iOS simulator build",
+            show_versions: &self.custom_inputs.show_versions,
+            xcode_scheme: &self
+                .custom_inputs
+                .xcode_scheme
+                .as_ref()
+                .unwrap_or(&String::new()),
+            xcode_project_path: &self
+                .custom_inputs
+                .xcode_project_path
+                .as_ref()
+                .unwrap_or(&String::new()),
+            package_manager: &self.custom_inputs.package_manager,
+            node_version_source: &self.custom_inputs.node_version_source,
+            node_version: &self
+                .custom_inputs
+                .node_version
+                .as_ref()
+                .unwrap_or(&String::new()),
+            project_root: &self.project_root(),
         }
         .render()
         .unwrap()
     }
 
-    fn github_react_native_signed_info(&self) -> String {
-        GithubReactNativeSignedInfo {
-            show_versions: &self.custom_inputs.show_versions.to_owned(),
+    fn github_kotlin_multiplatform_signed(&self) -> String {
+        GithubKotlinMultiplatformSigned {
+            title: "Kotlin Multiplatform Android release build",
+            publishing_formats: &self.custom_inputs.publishing_formats,
+            show_versions: &self.custom_inputs.show_versions,
+            versioning_strategy: &self.versioning_strategy(),
+            version_code_offset: &self.version_code_offset(),
+            build_variant_name: &self
+                .custom_inputs
+                .build_variant_name
+                .as_ref()
+                .unwrap_or(&String::new()),
+            build_variant_path: &self.build_variant_apk_path(),
+            build_variant_bundle_path: &self
+                .custom_inputs
+                .build_variant_bundle_path
+                .as_ref()
+                .unwrap_or(&String::new()),
+            kmp_android_module: &self
+                .custom_inputs
+                .kmp_android_module
+                .as_ref()
+                .unwrap_or(&String::new()),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            universal_apk: &(self.custom_inputs.universal_apk && self.supports_universal_apk()),
+            exclude_universal_split: &self.excludes_universal_split(),
+            build_variant_dir: &self.build_variant_dir(),
+            upload_mapping: &(self.custom_inputs.upload_mapping && self.supports_debug_symbols()),
+            upload_native_symbols: &(self.custom_inputs.upload_native_symbols
+                && self.supports_debug_symbols()),
+            gradle_signing: &self.uses_gradle_signing(),
+            verify_signature: &(self.custom_inputs.verify_signature
+                && self.supports_signature_verification()),
+            project_root: &self.project_root(),
         }
         .render()
         .unwrap()
     }
 
-    fn github_native_signed(&self) -> String {
-        GithubNativeSigned {
-            title: "Android release build",
-            publishing_format: &self.custom_inputs.publishing_format,
+    fn github_kotlin_multiplatform_unsigned(&self) -> String {
+        GithubKotlinMultiplatformUnsigned {
+            title: "Kotlin Multiplatform Android debug build",
+            publishing_formats: &self.custom_inputs.publishing_formats,
             show_versions: &self.custom_inputs.show_versions,
+            versioning_strategy: &self.versioning_strategy(),
+            version_code_offset: &self.version_code_offset(),
             build_variant_name: &self
                 .custom_inputs
                 .build_variant_name
                 .as_ref()
                 .unwrap_or(&String::new()),
-            build_variant_path: &self
+            build_variant_path: &self.build_variant_apk_path(),
+            build_variant_bundle_path: &self
                 .custom_inputs
-                .build_variant_path
+                .build_variant_bundle_path
+                .as_ref()
+                .unwrap_or(&String::new()),
+            kmp_android_module: &self
+                .custom_inputs
+                .kmp_android_module
                 .as_ref()
                 .unwrap_or(&String::new()),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            exclude_universal_split: &self.excludes_universal_split(),
+            project_root: &self.project_root(),
         }
         .render()
         .unwrap()
     }
 
-    fn github_flutter_signed(&self) -> String {
-        GithubFlutterSigned {
-            title: "Flutter Android release build",
-            publishing_format: &self.custom_inputs.publishing_format,
+    fn github_ios_kotlin_multiplatform_unsigned(&self) -> String {
+        GithubIosKotlinMultiplatformUnsigned {
+            title: "Kotlin Multiplatform iOS simulator build",
             show_versions: &self.custom_inputs.show_versions,
-            build_variant_path: &self
+            xcode_scheme: &self
                 .custom_inputs
-                .build_variant_path
+                .xcode_scheme
+                .as_ref()
+                .unwrap_or(&String::new()),
+            xcode_project_path: &self
+                .custom_inputs
+                .xcode_project_path
+                .as_ref()
+                .unwrap_or(&String::new()),
+            kmp_shared_module: &self
+                .custom_inputs
+                .kmp_shared_module
                 .as_ref()
                 .unwrap_or(&String::new()),
+            kmp_ios_framework: &self.custom_inputs.kmp_ios_framework,
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            project_root: &self.project_root(),
         }
         .render()
         .unwrap()
     }
 
-    fn github_react_native_signed(&self) -> String {
-        GithubReactNativeSigned {
-            title: "React Native Android release build",
-            publishing_format: &self.custom_inputs.publishing_format,
+    fn github_maui_signed(&self) -> String {
+        GithubMauiSigned {
+            title: "MAUI Android release build",
+            publishing_formats: &self.custom_inputs.publishing_formats,
             show_versions: &self.custom_inputs.show_versions,
+            maui_project: &self
+                .custom_inputs
+                .maui_project
+                .as_ref()
+                .unwrap_or(&String::new()),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_maui_unsigned(&self) -> String {
+        GithubMauiUnsigned {
+            title: "MAUI Android debug build",
+            publishing_formats: &self.custom_inputs.publishing_formats,
+            show_versions: &self.custom_inputs.show_versions,
+            maui_project: &self
+                .custom_inputs
+                .maui_project
+                .as_ref()
+                .unwrap_or(&String::new()),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_capacitor_signed(&self) -> String {
+        GithubCapacitorSigned {
+            title: "Capacitor Android release build",
+            publishing_formats: &self.custom_inputs.publishing_formats,
+            show_versions: &self.custom_inputs.show_versions,
+            versioning_strategy: &self.versioning_strategy(),
+            version_code_offset: &self.version_code_offset(),
             build_variant_name: &self
                 .custom_inputs
                 .build_variant_name
                 .as_ref()
                 .unwrap_or(&String::new()),
-            build_variant_path: &self
+            build_variant_path: &self.build_variant_apk_path(),
+            build_variant_bundle_path: &self
                 .custom_inputs
-                .build_variant_path
+                .build_variant_bundle_path
                 .as_ref()
                 .unwrap_or(&String::new()),
+            package_manager: &self.custom_inputs.package_manager,
+            node_version_source: &self.custom_inputs.node_version_source,
+            node_version: &self
+                .custom_inputs
+                .node_version
+                .as_ref()
+                .unwrap_or(&String::new()),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            universal_apk: &(self.custom_inputs.universal_apk && self.supports_universal_apk()),
+            exclude_universal_split: &self.excludes_universal_split(),
+            build_variant_dir: &self.build_variant_dir(),
+            upload_mapping: &(self.custom_inputs.upload_mapping && self.supports_debug_symbols()),
+            upload_native_symbols: &(self.custom_inputs.upload_native_symbols
+                && self.supports_debug_symbols()),
+            gradle_signing: &self.uses_gradle_signing(),
+            verify_signature: &(self.custom_inputs.verify_signature
+                && self.supports_signature_verification()),
+            project_root: &self.project_root(),
         }
         .render()
         .unwrap()
     }
 
-    fn github_native_unsigned(&self) -> String {
-        GithubNativeUnsigned {
-            title: "Android debug build",
-            publishing_format: &self.custom_inputs.publishing_format,
+    fn github_capacitor_unsigned(&self) -> String {
+        GithubCapacitorUnsigned {
+            title: "Capacitor Android debug build",
+            publishing_formats: &self.custom_inputs.publishing_formats,
             show_versions: &self.custom_inputs.show_versions,
+            versioning_strategy: &self.versioning_strategy(),
+            version_code_offset: &self.version_code_offset(),
             build_variant_name: &self
                 .custom_inputs
                 .build_variant_name
                 .as_ref()
                 .unwrap_or(&String::new()),
-            build_variant_path: &self
+            build_variant_path: &self.build_variant_apk_path(),
+            build_variant_bundle_path: &self
                 .custom_inputs
-                .build_variant_path
+                .build_variant_bundle_path
                 .as_ref()
                 .unwrap_or(&String::new()),
+            package_manager: &self.custom_inputs.package_manager,
+            node_version_source: &self.custom_inputs.node_version_source,
+            node_version: &self
+                .custom_inputs
+                .node_version
+                .as_ref()
+                .unwrap_or(&String::new()),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            exclude_universal_split: &self.excludes_universal_split(),
+            project_root: &self.project_root(),
         }
         .render()
         .unwrap()
     }
 
-    fn github_flutter_unsigned(&self) -> String {
-        GithubFlutterUnsigned {
-            title: "Flutter Android debug build",
-            publishing_format: &self.custom_inputs.publishing_format,
+    fn github_unity_signed(&self) -> String {
+        GithubUnitySigned {
+            title: "Unity Android release build",
+            publishing_format: &self.publishing_format(),
             show_versions: &self.custom_inputs.show_versions,
-            build_variant_path: &self
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_unity_unsigned(&self) -> String {
+        GithubUnityUnsigned {
+            title: "Unity Android debug build",
+            publishing_format: &self.publishing_format(),
+            show_versions: &self.custom_inputs.show_versions,
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_tauri_signed(&self) -> String {
+        GithubTauriSigned {
+            title: "Tauri Android release build",
+            publishing_formats: &self.custom_inputs.publishing_formats,
+            show_versions: &self.custom_inputs.show_versions,
+            package_manager: &self.custom_inputs.package_manager,
+            node_version_source: &self.custom_inputs.node_version_source,
+            node_version: &self
                 .custom_inputs
-                .build_variant_path
+                .node_version
+                .as_ref()
+                .unwrap_or(&String::new()),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            universal_apk: &(self.custom_inputs.universal_apk && self.supports_universal_apk()),
+            upload_mapping: &(self.custom_inputs.upload_mapping && self.supports_debug_symbols()),
+            upload_native_symbols: &(self.custom_inputs.upload_native_symbols
+                && self.supports_debug_symbols()),
+            gradle_signing: &self.uses_gradle_signing(),
+            verify_signature: &(self.custom_inputs.verify_signature
+                && self.supports_signature_verification()),
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn github_tauri_unsigned(&self) -> String {
+        GithubTauriUnsigned {
+            title: "Tauri Android debug build",
+            publishing_formats: &self.custom_inputs.publishing_formats,
+            show_versions: &self.custom_inputs.show_versions,
+            package_manager: &self.custom_inputs.package_manager,
+            node_version_source: &self.custom_inputs.node_version_source,
+            node_version: &self
+                .custom_inputs
+                .node_version
+                .as_ref()
+                .unwrap_or(&String::new()),
+            jdk_version: &self.custom_inputs.jdk_version,
+            jdk_distribution: &self.custom_inputs.jdk_distribution,
+            gradle_setup: &self.custom_inputs.gradle_setup,
+            gradle_properties: &self.gradle_properties(),
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn xcode_cloud_native(&self) -> String {
+        XcodeCloudNative {
+            show_versions: &self.custom_inputs.show_versions,
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn xcode_cloud_flutter(&self) -> String {
+        XcodeCloudFlutter {
+            show_versions: &self.custom_inputs.show_versions,
+            flutter_build_flags: &self.flutter_build_flags(),
+            flutter_version_source: &self.custom_inputs.flutter_version_source,
+            flutter_version: &self
+                .custom_inputs
+                .flutter_version
                 .as_ref()
                 .unwrap_or(&String::new()),
+            project_root: &self.project_root(),
         }
         .render()
         .unwrap()
     }
 
-    fn github_react_native_unsigned(&self) -> String {
-        GithubReactNativeUnsigned {
-            title: "React Native Android debug build",
-            publishing_format: &self.custom_inputs.publishing_format,
-            show_versions: &self.custom_inputs.show_versions,
-            build_variant_name: &self
-                .custom_inputs
-                .build_variant_name
-                .as_ref()
-                .unwrap_or(&String::new()),
-            build_variant_path: &self
-                .custom_inputs
-                .build_variant_path
-                .as_ref()
-                .unwrap_or(&String::new()),
+    fn xcode_cloud_react_native(&self) -> String {
+        XcodeCloudReactNative {
+            show_versions: &self.custom_inputs.show_versions,
+            package_manager: &self.custom_inputs.package_manager,
+            node_version_source: &self.custom_inputs.node_version_source,
+            node_version: &self.node_major_version(),
+            project_root: &self.project_root(),
+        }
+        .render()
+        .unwrap()
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
+)]
+pub enum AppPlatform {
+    #[strum(serialize = "Android")]
+    Android,
+    #[strum(serialize = "iOS")]
+    Ios,
+}
+
+#[derive(
+    Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
+)]
+pub enum Platform {
+    #[strum(serialize = "GitHub Actions")]
+    GitHub,
+    #[strum(serialize = "Xcode Cloud")]
+    XcodeCloud,
+}
+
+impl Platform {
+    pub fn supports(&self, app_platform: AppPlatform) -> bool {
+        match self {
+            Platform::GitHub => true,
+            Platform::XcodeCloud => matches!(app_platform, AppPlatform::Ios),
+        }
+    }
+
+    pub fn code_language(&self) -> &'static str {
+        match self {
+            Platform::GitHub => "yaml",
+            Platform::XcodeCloud => "bash",
+        }
+    }
+
+    pub fn code_label(&self) -> &'static str {
+        match self {
+            Platform::GitHub => "YAML",
+            Platform::XcodeCloud => "Shell",
+        }
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
+)]
+pub enum Sdk {
+    #[strum(serialize = "Native App")]
+    Native,
+    #[strum(serialize = "Flutter")]
+    Flutter,
+    #[strum(serialize = "React Native")]
+    ReactNative,
+    #[strum(serialize = "Expo")]
+    Expo,
+    #[strum(serialize = "Kotlin Multiplatform")]
+    KotlinMultiplatform,
+    #[strum(serialize = ".NET MAUI")]
+    Maui,
+    #[strum(serialize = "Capacitor")]
+    Capacitor,
+    #[strum(serialize = "Unity")]
+    Unity,
+    #[strum(serialize = "Tauri")]
+    Tauri,
+}
+
+impl Sdk {
+    pub fn supports(&self, app_platform: AppPlatform, platform: Platform) -> bool {
+        match self {
+            Sdk::Native | Sdk::Flutter | Sdk::ReactNative => true,
+            Sdk::Expo | Sdk::Maui | Sdk::Capacitor | Sdk::Unity | Sdk::Tauri => {
+                matches!(app_platform, AppPlatform::Android) && matches!(platform, Platform::GitHub)
+            }
+            Sdk::KotlinMultiplatform => matches!(platform, Platform::GitHub),
+        }
+    }
+
+    pub fn uses_package_manager(&self) -> bool {
+        matches!(
+            self,
+            Sdk::ReactNative | Sdk::Expo | Sdk::Capacitor | Sdk::Tauri
+        )
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
+)]
+pub enum BuildType {
+    #[strum(serialize = "Debug (unsigned)")]
+    Unsigned,
+    #[strum(serialize = "Release (signed)")]
+    Signed,
+}
+
+impl BuildType {
+    pub fn supports(&self, app_platform: AppPlatform) -> bool {
+        match self {
+            BuildType::Unsigned => true,
+            BuildType::Signed => matches!(app_platform, AppPlatform::Android),
+        }
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
+)]
+pub enum PublishingFormat {
+    #[strum(serialize = "APK")]
+    Apk,
+    #[strum(serialize = "AAB")]
+    Aab,
+}
+
+#[derive(
+    Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
+)]
+pub enum SigningStrategy {
+    #[strum(serialize = "key.properties file")]
+    KeyProperties,
+    #[strum(serialize = "Gradle properties")]
+    GradleProperties,
+}
+
+#[derive(
+    Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
+)]
+pub enum GradleDsl {
+    #[strum(serialize = "Groovy (build.gradle)")]
+    Groovy,
+    #[strum(serialize = "Kotlin (build.gradle.kts)")]
+    Kotlin,
+}
+
+#[derive(
+    Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
+)]
+pub enum VersioningStrategy {
+    #[strum(serialize = "Manual input")]
+    Manual,
+    #[strum(serialize = "Workflow run number")]
+    RunNumber,
+    #[strum(serialize = "Git commit count")]
+    CommitCount,
+    #[strum(serialize = "UTC timestamp")]
+    Timestamp,
+    #[strum(serialize = "Latest git tag")]
+    GitTag,
+}
+
+#[derive(
+    Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
+)]
+pub enum ExpoBuildTool {
+    #[strum(serialize = "EAS local build")]
+    EasLocal,
+    #[strum(serialize = "Expo prebuild + Gradle")]
+    Prebuild,
+}
+
+#[derive(
+    Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
+)]
+pub enum KmpIosFramework {
+    #[strum(serialize = "Direct integration")]
+    EmbedAndSign,
+    #[strum(serialize = "XCFramework")]
+    XcFramework,
+}
+
+#[derive(
+    Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
+)]
+pub enum PackageManager {
+    #[strum(serialize = "npm")]
+    Npm,
+    #[strum(serialize = "Yarn (classic)")]
+    YarnClassic,
+    #[strum(serialize = "Yarn (berry)")]
+    YarnBerry,
+    #[strum(serialize = "pnpm")]
+    Pnpm,
+    #[strum(serialize = "Bun")]
+    Bun,
+    #[strum(serialize = "Detect from lockfile")]
+    Detect,
+}
+
+// The Detect variant reads these from the outputs of the lockfile detection step
+impl PackageManager {
+    pub fn cache(&self) -> &'static str {
+        match self {
+            PackageManager::Npm => "npm",
+            PackageManager::YarnClassic | PackageManager::YarnBerry => "yarn",
+            PackageManager::Pnpm => "pnpm",
+            PackageManager::Bun => "",
+            PackageManager::Detect => "${{ steps.detect-package-manager.outputs.cache }}",
+        }
+    }
+
+    pub fn install_command(&self) -> &'static str {
+        match self {
+            PackageManager::Npm => "npm ci",
+            PackageManager::YarnClassic => "yarn install --frozen-lockfile",
+            PackageManager::YarnBerry => "yarn install --immutable",
+            PackageManager::Pnpm => "pnpm install --frozen-lockfile",
+            PackageManager::Bun => "bun install --frozen-lockfile",
+            PackageManager::Detect => "${{ steps.detect-package-manager.outputs.install }}",
+        }
+    }
+
+    // Yarn and pnpm come with Node through Corepack, so they don't need a separate install
+    pub fn uses_corepack(&self) -> bool {
+        matches!(
+            self,
+            PackageManager::YarnClassic | PackageManager::YarnBerry | PackageManager::Pnpm
+        )
+    }
+
+    pub fn run_command(&self) -> &'static str {
+        match self {
+            PackageManager::Npm => "npm run",
+            PackageManager::YarnClassic | PackageManager::YarnBerry => "yarn",
+            PackageManager::Pnpm => "pnpm",
+            PackageManager::Bun => "bun run",
+            PackageManager::Detect => "${{ steps.detect-package-manager.outputs.run }}",
+        }
+    }
+
+    pub fn exec_command(&self) -> &'static str {
+        match self {
+            PackageManager::Npm => "npx",
+            PackageManager::YarnClassic | PackageManager::YarnBerry => "yarn",
+            PackageManager::Pnpm => "pnpm exec",
+            PackageManager::Bun => "bunx",
+            PackageManager::Detect => "${{ steps.detect-package-manager.outputs.exec }}",
+        }
+    }
+
+    pub fn lockfile(&self) -> &'static str {
+        match self {
+            PackageManager::Npm => "package-lock.json",
+            PackageManager::YarnClassic | PackageManager::YarnBerry => "yarn.lock",
+            PackageManager::Pnpm => "pnpm-lock.yaml",
+            PackageManager::Bun => "bun.lock*",
+            PackageManager::Detect => "${{ steps.detect-package-manager.outputs.lockfile }}",
         }
-        .render()
-        .unwrap()
     }
 }
 
 #[derive(
     Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
 )]
-pub enum AppPlatform {
-    #[strum(serialize = "Android")]
-    Android,
+pub enum NodeVersionSource {
+    #[strum(serialize = "Version")]
+    Version,
+    #[strum(serialize = ".nvmrc")]
+    Nvmrc,
+    #[strum(serialize = ".node-version")]
+    NodeVersionFile,
 }
 
 #[derive(
     Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
 )]
-pub enum Platform {
-    #[strum(serialize = "GitHub Actions")]
-    GitHub,
+pub enum FlutterVersionSource {
+    #[strum(serialize = "Channel")]
+    Channel,
+    #[strum(serialize = "Version")]
+    Version,
+    #[strum(serialize = "FVM config")]
+    Fvm,
 }
 
 #[derive(
     Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
 )]
-pub enum Sdk {
-    #[strum(serialize = "Native App")]
-    Native,
-    #[strum(serialize = "Flutter")]
-    Flutter,
-    #[strum(serialize = "React Native")]
-    ReactNative,
+pub enum JdkVersion {
+    #[strum(serialize = "11")]
+    Jdk11,
+    #[strum(serialize = "17")]
+    Jdk17,
+    #[strum(serialize = "21")]
+    Jdk21,
+}
+
+impl JdkVersion {
+    pub fn major(&self) -> u8 {
+        match self {
+            JdkVersion::Jdk11 => 11,
+            JdkVersion::Jdk17 => 17,
+            JdkVersion::Jdk21 => 21,
+        }
+    }
 }
 
 #[derive(
     Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
 )]
-pub enum BuildType {
-    #[strum(serialize = "Debug (unsigned)")]
-    Unsigned,
-    #[strum(serialize = "Release (signed)")]
-    Signed,
+pub enum JdkDistribution {
+    #[strum(serialize = "Zulu")]
+    Zulu,
+    #[strum(serialize = "Temurin")]
+    Temurin,
+    #[strum(serialize = "Microsoft")]
+    Microsoft,
+    #[strum(serialize = "Corretto")]
+    Corretto,
+    #[strum(serialize = "Oracle")]
+    Oracle,
+}
+
+impl JdkDistribution {
+    // The distribution identifiers accepted by actions/setup-java
+    pub fn setup_java(&self) -> &'static str {
+        match self {
+            JdkDistribution::Zulu => "zulu",
+            JdkDistribution::Temurin => "temurin",
+            JdkDistribution::Microsoft => "microsoft",
+            JdkDistribution::Corretto => "corretto",
+            JdkDistribution::Oracle => "oracle",
+        }
+    }
 }
 
 #[derive(
     Clone, Copy, Debug, EnumIter, EnumString, Display, PartialEq, Serialize, Deserialize, Eq,
 )]
-pub enum PublishingFormat {
-    #[strum(serialize = "APK")]
-    Apk,
-    #[strum(serialize = "AAB")]
-    Aab,
+pub enum RunnerSize {
+    #[strum(serialize = "Project default")]
+    Default,
+    #[strum(serialize = "2 cores, 7 GB (private repositories)")]
+    Small,
+    #[strum(serialize = "4 cores, 16 GB (public repositories)")]
+    Medium,
+    #[strum(serialize = "8 cores, 32 GB (larger runners)")]
+    Large,
+}
+
+impl RunnerSize {
+    // Leaves room for the Kotlin compile daemon and the rest of the runner
+    pub fn gradle_jvmargs(&self) -> &'static str {
+        match self {
+            RunnerSize::Default => "",
+            RunnerSize::Small => "-Xmx4g -XX:MaxMetaspaceSize=1g -XX:+HeapDumpOnOutOfMemoryError -Dfile.encoding=UTF-8",
+            RunnerSize::Medium => "-Xmx8g -XX:MaxMetaspaceSize=1g -XX:+HeapDumpOnOutOfMemoryError -Dfile.encoding=UTF-8",
+            RunnerSize::Large => "-Xmx16g -XX:MaxMetaspaceSize=2g -XX:+HeapDumpOnOutOfMemoryError -Dfile.encoding=UTF-8",
+        }
+    }
+}
+
+impl GradleDsl {
+    pub fn build_file(&self) -> &'static str {
+        match self {
+            GradleDsl::Groovy => "build.gradle",
+            GradleDsl::Kotlin => "build.gradle.kts",
+        }
+    }
+}
+
+// The latest git tag only sets the versionName, the other strategies only set the versionCode
+impl VersioningStrategy {
+    pub fn has_name_input(&self) -> bool {
+        !matches!(self, VersioningStrategy::GitTag)
+    }
+
+    pub fn has_code_input(&self) -> bool {
+        matches!(
+            self,
+            VersioningStrategy::Manual | VersioningStrategy::GitTag
+        )
+    }
+
+    pub fn fetches_history(&self) -> bool {
+        matches!(
+            self,
+            VersioningStrategy::CommitCount | VersioningStrategy::GitTag
+        )
+    }
+}
+
+// Minimum JDK for each Android Gradle Plugin release line, newest first
+const AGP_MIN_JDK: [((u32, u32), u8); 3] = [((8, 0), 17), ((7, 0), 11), ((0, 0), 8)];
+
+struct BuildVariant {
+    name: String,
+    task: String,
+    apk_path: String,
+    aab_path: String,
+}
+
+enum DartDefineValue<'a> {
+    Literal(&'a str),
+    Secret(&'a str),
+}
+
+// Wraps a value in single quotes unless it's safe to pass to the shell as is
+fn shell_quote(value: &str) -> String {
+    let is_safe = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_-.,:/=@%+".contains(c));
+
+    if is_safe {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
+
+// Matches the names a POSIX shell accepts for environment variables
+fn is_env_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// Splits a comma or whitespace separated list, skipping empty entries
+fn split_list(value: &str) -> Vec<&str> {
+    value
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+// Puts a wildcard for the ABI before the build type, e.g. release/app-release to release/app-*-release
+fn abi_split_path(path: &str) -> String {
+    match path.rsplit_once('-') {
+        Some((name, build_type)) => format!("{}-*-{}", name, build_type),
+        None => format!("{}-*", path),
+    }
+}
+
+fn capitalize(value: &str) -> String {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
 }
 
 #[derive(Template)]
 #[template(path = "workflows/github-native-signed")]
 struct GithubNativeSigned<'a> {
     title: &'a str,
-    publishing_format: &'a PublishingFormat,
+    publishing_formats: &'a [PublishingFormat],
     show_versions: &'a bool,
+    versioning_strategy: &'a VersioningStrategy,
+    version_code_offset: &'a str,
     build_variant_name: &'a str,
     build_variant_path: &'a str,
+    build_variant_bundle_path: &'a str,
+    variants: &'a [BuildVariant],
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    universal_apk: &'a bool,
+    exclude_universal_split: &'a bool,
+    build_variant_dir: &'a str,
+    upload_mapping: &'a bool,
+    upload_native_symbols: &'a bool,
+    gradle_signing: &'a bool,
+    verify_signature: &'a bool,
+    project_root: &'a str,
 }
 
 #[derive(Template)]
 #[template(path = "workflows/github-flutter-signed")]
 struct GithubFlutterSigned<'a> {
     title: &'a str,
-    publishing_format: &'a PublishingFormat,
+    publishing_formats: &'a [PublishingFormat],
     show_versions: &'a bool,
-    build_variant_path: &'a str,
+    versioning_strategy: &'a VersioningStrategy,
+    version_code_offset: &'a str,
+    flutter_apk_path: &'a str,
+    flutter_aab_path: &'a str,
+    flutter_build_flags: &'a str,
+    abi_splits: &'a bool,
+    dart_define_flags: &'a str,
+    dart_define_secrets: &'a [String],
+    dart_defines_file_args: &'a str,
+    flutter_obfuscate: &'a bool,
+    flutter_version_source: &'a FlutterVersionSource,
+    flutter_version: &'a str,
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    universal_apk: &'a bool,
+    flutter_variant: &'a str,
+    upload_mapping: &'a bool,
+    upload_native_symbols: &'a bool,
+    gradle_signing: &'a bool,
+    verify_signature: &'a bool,
+    project_root: &'a str,
 }
 
 #[derive(Template)]
 #[template(path = "workflows/github-react-native-signed")]
 struct GithubReactNativeSigned<'a> {
     title: &'a str,
-    publishing_format: &'a PublishingFormat,
+    publishing_formats: &'a [PublishingFormat],
     show_versions: &'a bool,
+    versioning_strategy: &'a VersioningStrategy,
+    version_code_offset: &'a str,
     build_variant_name: &'a str,
     build_variant_path: &'a str,
+    build_variant_bundle_path: &'a str,
+    package_manager: &'a PackageManager,
+    node_version_source: &'a NodeVersionSource,
+    node_version: &'a str,
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    universal_apk: &'a bool,
+    exclude_universal_split: &'a bool,
+    build_variant_dir: &'a str,
+    upload_mapping: &'a bool,
+    upload_native_symbols: &'a bool,
+    gradle_signing: &'a bool,
+    verify_signature: &'a bool,
+    project_root: &'a str,
 }
 
 #[derive(Template)]
 #[template(path = "workflows/github-native-unsigned")]
 struct GithubNativeUnsigned<'a> {
     title: &'a str,
-    publishing_format: &'a PublishingFormat,
+    publishing_formats: &'a [PublishingFormat],
     show_versions: &'a bool,
+    versioning_strategy: &'a VersioningStrategy,
+    version_code_offset: &'a str,
     build_variant_name: &'a str,
     build_variant_path: &'a str,
+    build_variant_bundle_path: &'a str,
+    variants: &'a [BuildVariant],
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    exclude_universal_split: &'a bool,
+    project_root: &'a str,
 }
 
 #[derive(Template)]
 #[template(path = "workflows/github-flutter-unsigned")]
 struct GithubFlutterUnsigned<'a> {
     title: &'a str,
-    publishing_format: &'a PublishingFormat,
+    publishing_formats: &'a [PublishingFormat],
     show_versions: &'a bool,
-    build_variant_path: &'a str,
+    versioning_strategy: &'a VersioningStrategy,
+    version_code_offset: &'a str,
+    flutter_apk_path: &'a str,
+    flutter_aab_path: &'a str,
+    flutter_build_flags: &'a str,
+    abi_splits: &'a bool,
+    dart_define_flags: &'a str,
+    dart_define_secrets: &'a [String],
+    dart_defines_file_args: &'a str,
+    flutter_version_source: &'a FlutterVersionSource,
+    flutter_version: &'a str,
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    project_root: &'a str,
 }
 
 #[derive(Template)]
 #[template(path = "workflows/github-react-native-unsigned")]
 struct GithubReactNativeUnsigned<'a> {
+    title: &'a str,
+    publishing_formats: &'a [PublishingFormat],
+    show_versions: &'a bool,
+    versioning_strategy: &'a VersioningStrategy,
+    version_code_offset: &'a str,
+    build_variant_name: &'a str,
+    build_variant_path: &'a str,
+    build_variant_bundle_path: &'a str,
+    package_manager: &'a PackageManager,
+    node_version_source: &'a NodeVersionSource,
+    node_version: &'a str,
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    exclude_universal_split: &'a bool,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-expo-signed")]
+struct GithubExpoSigned<'a> {
+    title: &'a str,
+    publishing_format: &'a PublishingFormat,
+    show_versions: &'a bool,
+    versioning_strategy: &'a VersioningStrategy,
+    version_code_offset: &'a str,
+    build_variant_name: &'a str,
+    build_variant_path: &'a str,
+    build_variant_bundle_path: &'a str,
+    expo_build_tool: &'a ExpoBuildTool,
+    eas_profile: &'a str,
+    eas_update: &'a bool,
+    eas_cli: &'a bool,
+    package_manager: &'a PackageManager,
+    node_version_source: &'a NodeVersionSource,
+    node_version: &'a str,
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    gradle_signing: &'a bool,
+    verify_signature: &'a bool,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-expo-unsigned")]
+struct GithubExpoUnsigned<'a> {
     title: &'a str,
     publishing_format: &'a PublishingFormat,
     show_versions: &'a bool,
+    versioning_strategy: &'a VersioningStrategy,
+    version_code_offset: &'a str,
+    build_variant_name: &'a str,
+    build_variant_path: &'a str,
+    build_variant_bundle_path: &'a str,
+    expo_build_tool: &'a ExpoBuildTool,
+    eas_profile: &'a str,
+    eas_update: &'a bool,
+    eas_cli: &'a bool,
+    package_manager: &'a PackageManager,
+    node_version_source: &'a NodeVersionSource,
+    node_version: &'a str,
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-kotlin-multiplatform-signed")]
+struct GithubKotlinMultiplatformSigned<'a> {
+    title: &'a str,
+    publishing_formats: &'a [PublishingFormat],
+    show_versions: &'a bool,
+    versioning_strategy: &'a VersioningStrategy,
+    version_code_offset: &'a str,
+    build_variant_name: &'a str,
+    build_variant_path: &'a str,
+    build_variant_bundle_path: &'a str,
+    kmp_android_module: &'a str,
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    universal_apk: &'a bool,
+    exclude_universal_split: &'a bool,
+    build_variant_dir: &'a str,
+    upload_mapping: &'a bool,
+    upload_native_symbols: &'a bool,
+    gradle_signing: &'a bool,
+    verify_signature: &'a bool,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-kotlin-multiplatform-unsigned")]
+struct GithubKotlinMultiplatformUnsigned<'a> {
+    title: &'a str,
+    publishing_formats: &'a [PublishingFormat],
+    show_versions: &'a bool,
+    versioning_strategy: &'a VersioningStrategy,
+    version_code_offset: &'a str,
+    build_variant_name: &'a str,
+    build_variant_path: &'a str,
+    build_variant_bundle_path: &'a str,
+    kmp_android_module: &'a str,
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    exclude_universal_split: &'a bool,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-maui-signed")]
+struct GithubMauiSigned<'a> {
+    title: &'a str,
+    publishing_formats: &'a [PublishingFormat],
+    show_versions: &'a bool,
+    maui_project: &'a str,
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-maui-unsigned")]
+struct GithubMauiUnsigned<'a> {
+    title: &'a str,
+    publishing_formats: &'a [PublishingFormat],
+    show_versions: &'a bool,
+    maui_project: &'a str,
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-capacitor-signed")]
+struct GithubCapacitorSigned<'a> {
+    title: &'a str,
+    publishing_formats: &'a [PublishingFormat],
+    show_versions: &'a bool,
+    versioning_strategy: &'a VersioningStrategy,
+    version_code_offset: &'a str,
+    build_variant_name: &'a str,
+    build_variant_path: &'a str,
+    build_variant_bundle_path: &'a str,
+    package_manager: &'a PackageManager,
+    node_version_source: &'a NodeVersionSource,
+    node_version: &'a str,
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    universal_apk: &'a bool,
+    exclude_universal_split: &'a bool,
+    build_variant_dir: &'a str,
+    upload_mapping: &'a bool,
+    upload_native_symbols: &'a bool,
+    gradle_signing: &'a bool,
+    verify_signature: &'a bool,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-capacitor-unsigned")]
+struct GithubCapacitorUnsigned<'a> {
+    title: &'a str,
+    publishing_formats: &'a [PublishingFormat],
+    show_versions: &'a bool,
+    versioning_strategy: &'a VersioningStrategy,
+    version_code_offset: &'a str,
     build_variant_name: &'a str,
     build_variant_path: &'a str,
+    build_variant_bundle_path: &'a str,
+    package_manager: &'a PackageManager,
+    node_version_source: &'a NodeVersionSource,
+    node_version: &'a str,
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    exclude_universal_split: &'a bool,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-unity-signed")]
+struct GithubUnitySigned<'a> {
+    title: &'a str,
+    publishing_format: &'a PublishingFormat,
+    show_versions: &'a bool,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-unity-unsigned")]
+struct GithubUnityUnsigned<'a> {
+    title: &'a str,
+    publishing_format: &'a PublishingFormat,
+    show_versions: &'a bool,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-tauri-signed")]
+struct GithubTauriSigned<'a> {
+    title: &'a str,
+    publishing_formats: &'a [PublishingFormat],
+    show_versions: &'a bool,
+    package_manager: &'a PackageManager,
+    node_version_source: &'a NodeVersionSource,
+    node_version: &'a str,
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    universal_apk: &'a bool,
+    upload_mapping: &'a bool,
+    upload_native_symbols: &'a bool,
+    gradle_signing: &'a bool,
+    verify_signature: &'a bool,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-tauri-unsigned")]
+struct GithubTauriUnsigned<'a> {
+    title: &'a str,
+    publishing_formats: &'a [PublishingFormat],
+    show_versions: &'a bool,
+    package_manager: &'a PackageManager,
+    node_version_source: &'a NodeVersionSource,
+    node_version: &'a str,
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-ios-native-unsigned")]
+struct GithubIosNativeUnsigned<'a> {
+    title: &'a str,
+    show_versions: &'a bool,
+    xcode_scheme: &'a str,
+    xcode_project_path: &'a str,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-ios-flutter-unsigned")]
+struct GithubIosFlutterUnsigned<'a> {
+    title: &'a str,
+    show_versions: &'a bool,
+    flutter_build_flags: &'a str,
+    dart_define_flags: &'a str,
+    dart_define_secrets: &'a [String],
+    dart_defines_file_args: &'a str,
+    flutter_version_source: &'a FlutterVersionSource,
+    flutter_version: &'a str,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-ios-react-native-unsigned")]
+struct GithubIosReactNativeUnsigned<'a> {
+    title: &'a str,
+    show_versions: &'a bool,
+    xcode_scheme: &'a str,
+    xcode_project_path: &'a str,
+    package_manager: &'a PackageManager,
+    node_version_source: &'a NodeVersionSource,
+    node_version: &'a str,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/github-ios-kotlin-multiplatform-unsigned")]
+struct GithubIosKotlinMultiplatformUnsigned<'a> {
+    title: &'a str,
+    show_versions: &'a bool,
+    xcode_scheme: &'a str,
+    xcode_project_path: &'a str,
+    kmp_shared_module: &'a str,
+    kmp_ios_framework: &'a KmpIosFramework,
+    jdk_version: &'a JdkVersion,
+    jdk_distribution: &'a JdkDistribution,
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/xcode-cloud-native")]
+struct XcodeCloudNative<'a> {
+    show_versions: &'a bool,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/xcode-cloud-flutter")]
+struct XcodeCloudFlutter<'a> {
+    show_versions: &'a bool,
+    flutter_build_flags: &'a str,
+    flutter_version_source: &'a FlutterVersionSource,
+    flutter_version: &'a str,
+    project_root: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "workflows/xcode-cloud-react-native")]
+struct XcodeCloudReactNative<'a> {
+    show_versions: &'a bool,
+    package_manager: &'a PackageManager,
+    node_version_source: &'a NodeVersionSource,
+    node_version: &'a str,
+    project_root: &'a str,
 }
 
 #[derive(Template)]
 #[template(path = "info/github-native-signed")]
 struct GithubNativeSignedInfo<'a> {
     show_versions: &'a bool,
+    gradle_dsl: &'a GradleDsl,
+    versioning_info: &'a str,
+    signature_verification_info: &'a str,
+    abi_splits_info: &'a str,
+    gradle_signing_info: &'a str,
+    gradle_info: &'a str,
 }
 
 #[derive(Template)]
 #[template(path = "info/github-native-unsigned")]
 struct GithubNativeUnsignedInfo<'a> {
     show_versions: &'a bool,
+    gradle_dsl: &'a GradleDsl,
 }
 
 #[derive(Template)]
 #[template(path = "info/github-flutter-signed")]
 struct GithubFlutterSignedInfo<'a> {
     show_versions: &'a bool,
+    gradle_dsl: &'a GradleDsl,
+    flutter_obfuscate: &'a bool,
+    versioning_info: &'a str,
+    signature_verification_info: &'a str,
+    abi_splits_info: &'a str,
+    gradle_signing_info: &'a str,
+    gradle_info: &'a str,
 }
 
 #[derive(Template)]
@@ -331,10 +2753,157 @@ struct GithubFlutterUnsignedInfo<'a> {
 #[template(path = "info/github-react-native-signed")]
 struct GithubReactNativeSignedInfo<'a> {
     show_versions: &'a bool,
+    gradle_dsl: &'a GradleDsl,
+    versioning_info: &'a str,
+    signature_verification_info: &'a str,
+    abi_splits_info: &'a str,
+    gradle_signing_info: &'a str,
+    gradle_info: &'a str,
 }
 
 #[derive(Template)]
 #[template(path = "info/github-react-native-unsigned")]
 struct GithubReactNativeUnsignedInfo<'a> {
     show_versions: &'a bool,
+    gradle_dsl: &'a GradleDsl,
+}
+
+#[derive(Template)]
+#[template(path = "info/github-expo-signed")]
+struct GithubExpoSignedInfo<'a> {
+    show_versions: &'a bool,
+    expo_build_tool: &'a ExpoBuildTool,
+    eas_update: &'a bool,
+    eas_cli: &'a bool,
+    gradle_signing_info: &'a str,
+    versioning_info: &'a str,
+    signature_verification_info: &'a str,
+    gradle_info: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "info/github-expo-unsigned")]
+struct GithubExpoUnsignedInfo<'a> {
+    show_versions: &'a bool,
+    expo_build_tool: &'a ExpoBuildTool,
+    eas_update: &'a bool,
+    eas_cli: &'a bool,
+    versioning_info: &'a str,
+    gradle_info: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "info/github-kotlin-multiplatform-signed")]
+struct GithubKotlinMultiplatformSignedInfo<'a> {
+    show_versions: &'a bool,
+    kmp_android_module: &'a str,
+    versioning_info: &'a str,
+    signature_verification_info: &'a str,
+    abi_splits_info: &'a str,
+    gradle_signing_info: &'a str,
+    gradle_info: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "info/github-maui-signed")]
+struct GithubMauiSignedInfo<'a> {
+    show_versions: &'a bool,
+}
+
+#[derive(Template)]
+#[template(path = "info/github-capacitor-signed")]
+struct GithubCapacitorSignedInfo<'a> {
+    show_versions: &'a bool,
+    gradle_dsl: &'a GradleDsl,
+    versioning_info: &'a str,
+    signature_verification_info: &'a str,
+    abi_splits_info: &'a str,
+    gradle_signing_info: &'a str,
+    gradle_info: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "info/github-unity-signed")]
+struct GithubUnitySignedInfo<'a> {
+    show_versions: &'a bool,
+}
+
+#[derive(Template)]
+#[template(path = "info/github-unity-unsigned")]
+struct GithubUnityUnsignedInfo<'a> {
+    show_versions: &'a bool,
+}
+
+#[derive(Template)]
+#[template(path = "info/github-tauri-signed")]
+struct GithubTauriSignedInfo<'a> {
+    show_versions: &'a bool,
+    gradle_signing_info: &'a str,
+    signature_verification_info: &'a str,
+    gradle_info: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "info/xcode-cloud-native")]
+struct XcodeCloudNativeInfo<'a> {
+    show_versions: &'a bool,
+}
+
+#[derive(Template)]
+#[template(path = "info/xcode-cloud-flutter")]
+struct XcodeCloudFlutterInfo<'a> {
+    show_versions: &'a bool,
+}
+
+#[derive(Template)]
+#[template(path = "info/xcode-cloud-react-native")]
+struct XcodeCloudReactNativeInfo<'a> {
+    show_versions: &'a bool,
+}
+
+#[derive(Template)]
+#[template(path = "info/gradle-options")]
+struct GradleOptionsInfo<'a> {
+    gradle_setup: &'a bool,
+    gradle_properties: &'a [String],
+    gradle_configuration_cache: &'a bool,
+    gradle_build_cache: &'a bool,
+    gradle_parallel: &'a bool,
+    gradle_jvmargs: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "info/github-gradle-unsigned")]
+struct GithubGradleUnsignedInfo<'a> {
+    unsigned_versioning_info: &'a str,
+    versioning_info: &'a str,
+    abi_splits_info: &'a str,
+    gradle_info: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "info/abi-splits")]
+struct AbiSplitsInfo<'a> {
+    flutter: &'a bool,
+    gradle_dsl: &'a GradleDsl,
+    abi_splits_universal: &'a bool,
+}
+
+#[derive(Template)]
+#[template(path = "info/gradle-signing")]
+struct GradleSigningInfo<'a> {
+    build_file: &'a str,
+    kotlin_dsl: &'a bool,
+    injected: &'a bool,
+}
+
+#[derive(Template)]
+#[template(path = "info/signature-verification")]
+struct SignatureVerificationInfo {}
+
+#[derive(Template)]
+#[template(path = "info/versioning-strategy")]
+struct VersioningStrategyInfo<'a> {
+    versioning_strategy: &'a VersioningStrategy,
+    version_code_offset: &'a str,
 }